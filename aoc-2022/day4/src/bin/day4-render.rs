@@ -0,0 +1,56 @@
+//! Draws the section assignments of a day 4 input.
+//!
+//! `day4-render <input> [--color] [--svg <output>] [--analyse]` prints the groups to
+//! the terminal, writes them to an SVG file with `--svg`, or with `--analyse` reports
+//! how the sections are assigned across every group instead.
+
+use std::{env, error::Error, fs::{self, File}, io::BufReader, process::ExitCode};
+
+use day4::{analyse_assignments, read_groups, render_svg, render_text};
+
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let mut input = None;
+    let mut svg = None;
+    let mut color = false;
+    let mut analyse = false;
+
+    let mut arguments = env::args().skip(1);
+
+    while let Some(argument) = arguments.next() {
+        match argument.as_str() {
+            "--analyse" => analyse = true,
+            "--color" => color = true,
+            "--svg" => svg = Some(arguments.next().ok_or("--svg needs an output path")?),
+            _ if input.is_none() => input = Some(argument),
+            _ => return Err(format!("unexpected argument `{argument}`").into())
+        }
+    }
+
+    let input = input.ok_or("usage: day4-render <input> [--color] [--svg <output>] [--analyse]")?;
+
+    if analyse {
+        print!("{}", analyse_assignments(BufReader::new(File::open(&input)?))?);
+        return Ok(());
+    }
+
+    let groups = read_groups(BufReader::new(File::open(&input)?))?;
+
+    match svg {
+        Some(output) => fs::write(output, render_svg(&groups)?)?,
+        None => print!("{}", render_text(&groups, color)?)
+    }
+
+    Ok(())
+}
+
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,287 @@
+use std::{fmt, io::BufRead, ops::RangeInclusive};
+
+use aoc_core::{Answer, Result, Solution};
+use aoc_utils::{IntervalSet, IntervalTree, RangeInclusiveExtension};
+
+mod reader;
+mod render;
+
+pub use reader::{Assignment, AssignmentReader, GroupReader};
+pub use render::{Highlight, render_svg, render_text};
+
+
+/// The ranges of the elves working together, two of them in the original puzzle.
+pub type Group = Vec<RangeInclusive<u32>>;
+
+/// Reads every group of assignments into memory.
+pub fn read_groups(buffered_reader: impl BufRead) -> Result<Vec<Group>> {
+    let mut reader = GroupReader::new(buffered_reader);
+    let mut groups = Vec::new();
+
+    while let Some(group) = reader.next_group()? {
+        groups.push(group.to_vec());
+    }
+
+    Ok(groups)
+}
+
+/// Whether a range of the group holds every section of another one.
+fn any_contained(group: &[RangeInclusive<u32>]) -> bool {
+    group.iter()
+        .enumerate()
+        .any(|(index, range)| group.iter()
+            .enumerate()
+            .any(|(other_index, other)| other_index != index && other.contains_range(range))
+        )
+}
+
+/// The sections assigned to every elf of the group.
+fn common_sections(group: &[RangeInclusive<u32>]) -> Option<RangeInclusive<u32>> {
+    let (first, rest) = group.split_first()?;
+
+    rest.iter().try_fold(first.clone(), |common, range| common.intersection(range))
+}
+
+fn solve_part_1(groups: &[Group]) -> i32 {
+    let mut result = 0;
+
+    for group in groups {
+        if any_contained(group) {
+            result += 1;
+        }
+    }
+
+    result
+}
+
+fn solve_part_2(groups: &[Group]) -> i32 {
+    let mut result = 0;
+
+    for group in groups {
+        if common_sections(group).is_some() {
+            result += 1;
+        }
+    }
+
+    result
+}
+
+/// Answers both parts while reading, keeping a single group in memory at a time.
+pub fn solve_streaming(buffered_reader: impl BufRead) -> Result<(i32, i32)> {
+    let mut reader = GroupReader::new(buffered_reader);
+    let mut results = (0, 0);
+
+    while let Some(group) = reader.next_group()? {
+        results.0 += i32::from(any_contained(group));
+        results.1 += i32::from(common_sections(group).is_some());
+    }
+
+    Ok(results)
+}
+
+/// An elf, by the line of its group and its position in the group, both counted from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Elf {
+    pub line: usize,
+    pub position: usize
+}
+
+impl fmt::Display for Elf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {} elf {}", self.line, self.position)
+    }
+}
+
+/// Questions about the assignments of every elf at once rather than group by group.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Analysis {
+    /// Sections assigned to at least one elf
+    pub covered: u128,
+    /// Sections assigned to nobody between the lowest and the highest assigned one
+    pub unassigned: Vec<RangeInclusive<u32>>,
+    /// The lowest of the sections assigned to the most elves, and how many elves that is
+    pub busiest: Option<(u32, usize)>,
+    /// Elves whose sections are all assigned to a single other elf as well
+    pub redundant: Vec<Elf>
+}
+
+impl fmt::Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Sections covered: {}", self.covered)?;
+
+        let unassigned: Vec<String> = self.unassigned.iter()
+            .map(|range| format!("{}-{}", range.start(), range.end()))
+            .collect();
+        writeln!(f, "Sections assigned to nobody: {}", if unassigned.is_empty() { "none".to_owned() } else { unassigned.join(", ") })?;
+
+        if let Some((section, elves)) = self.busiest {
+            writeln!(f, "Busiest section: {section}, assigned to {elves} elves")?;
+        }
+
+        let redundant: Vec<String> = self.redundant.iter().map(Elf::to_string).collect();
+        writeln!(f, "Redundant elves ({}): {}", redundant.len(), redundant.join(", "))
+    }
+}
+
+fn elves(groups: &[Group]) -> impl Iterator<Item = (RangeInclusive<u32>, Elf)> + '_ {
+    groups.iter()
+        .enumerate()
+        .flat_map(|(index, group)| group.iter()
+            .enumerate()
+            .map(move |(position, range)| (range.clone(), Elf { line: index + 1, position: position + 1 }))
+        )
+}
+
+fn busiest_section(groups: &[Group]) -> Option<(u32, usize)> {
+    // every range adds an elf at its start and takes it away right after its end
+    let mut changes: Vec<(u64, isize)> = elves(groups)
+        .filter(|(range, _)| !range.is_empty())
+        .flat_map(|(range, _)| [(u64::from(*range.start()), 1), (u64::from(*range.end()) + 1, -1)])
+        .collect();
+    changes.sort();
+
+    let mut elves = 0;
+    let mut busiest: Option<(u32, usize)> = None;
+
+    for (section, change) in changes {
+        elves += change;
+
+        if busiest.is_none_or(|(_, most)| elves as usize > most) {
+            busiest = Some((section as u32, elves as usize));
+        }
+    }
+
+    busiest
+}
+
+fn redundant_elves(groups: &[Group]) -> Vec<Elf> {
+    let tree: IntervalTree<u32, Elf> = elves(groups).collect();
+
+    // of two elves with the same sections only the later one is redundant
+    let mut redundant: Vec<Elf> = tree.iter()
+        .filter(|&(range, elf)| tree.overlapping(range).into_iter().any(|(other_range, other)| {
+            other != elf && other_range.contains_range(range) && (other_range != range || other < elf)
+        }))
+        .map(|(_, &elf)| elf)
+        .collect();
+    redundant.sort();
+
+    redundant
+}
+
+/// Analyses already parsed groups of assignments together.
+pub fn analyse(groups: &[Group]) -> Analysis {
+    let sections: IntervalSet<u32> = elves(groups).map(|(range, _)| range).collect();
+
+    Analysis {
+        covered: sections.coverage(),
+        unassigned: sections.gaps().collect(),
+        busiest: busiest_section(groups),
+        redundant: redundant_elves(groups)
+    }
+}
+
+/// Reads every group of assignments and analyses them all together.
+pub fn analyse_assignments(buffered_reader: impl BufRead) -> Result<Analysis> {
+    Ok(analyse(&read_groups(buffered_reader)?))
+}
+
+#[cfg(feature = "embedded")]
+pub const INPUT: &str = include_str!("input.txt");
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Group>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        read_groups(input.as_bytes())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(solve_part_1(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(solve_part_2(input).into())
+    }
+
+    #[cfg(feature = "embedded")]
+    fn embedded_input(&self) -> Option<&'static str> {
+        Some(INPUT)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_core::{AocError, Result};
+    use crate::{Elf, Group, analyse_assignments, read_groups, solve_part_1, solve_part_2, solve_streaming};
+
+    const EXAMPLE: &str = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n";
+
+    fn parse_groups(input: &str) -> Result<Vec<Group>> {
+        read_groups(input.as_bytes())
+    }
+
+    #[test]
+    fn test_parse_groups() {
+        assert_eq!(parse_groups("2-4,6-8\n2-4,6-8,1-9").unwrap(), vec![vec![2..=4, 6..=8], vec![2..=4, 6..=8, 1..=9]]);
+
+        let error = parse_groups("1-1,1-1\n1-1,1-1\n2-4,6-x").unwrap_err();
+        assert!(matches!(error, AocError::Parse { line: 3, column: 7, .. }), "{error}");
+
+        let error = parse_groups("2-4").unwrap_err();
+        assert!(matches!(error, AocError::Parse { line: 1, column: 1, .. }), "{error}");
+
+        let error = parse_groups("2-4,68").unwrap_err();
+        assert!(matches!(error, AocError::Parse { line: 1, column: 7, .. }), "{error}");
+
+        let error = parse_groups("2-4,6-8,x-9").unwrap_err();
+        assert!(matches!(error, AocError::Parse { line: 1, column: 9, .. }), "{error}");
+    }
+
+    #[test]
+    fn test_groups() {
+        let input = "2-4,6-8\n1-9,2-4,3-8\n1-3,2-5,3-9\n1-3,2-5,4-9\n";
+        let groups = parse_groups(input).unwrap();
+
+        assert_eq!(solve_part_1(&groups), 1);
+        assert_eq!(solve_part_2(&groups), 2);
+        assert_eq!(solve_streaming(input.as_bytes()).unwrap(), (1, 2));
+
+        let groups = parse_groups(EXAMPLE).unwrap();
+        assert_eq!(solve_part_1(&groups), 2);
+        assert_eq!(solve_part_2(&groups), 4);
+        assert_eq!(solve_streaming(EXAMPLE.as_bytes()).unwrap(), (2, 4));
+
+        let error = solve_streaming("2-4,6-8\n5-3,6-8\n".as_bytes()).unwrap_err();
+        assert!(matches!(error, AocError::Parse { line: 2, column: 1, .. }), "{error}");
+    }
+
+    #[test]
+    fn test_analyse_assignments() {
+        let analysis = analyse_assignments(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(analysis.covered, 8);
+        assert!(analysis.unassigned.is_empty());
+        assert_eq!(analysis.busiest, Some((6, 8)));
+        assert_eq!(analysis.redundant.len(), 10);
+        assert!(!analysis.redundant.contains(&Elf { line: 3, position: 2 }));
+        assert!(!analysis.redundant.contains(&Elf { line: 4, position: 1 }));
+
+        let analysis = analyse_assignments("1-2,8-9\n4-4,8-9\n".as_bytes()).unwrap();
+
+        assert_eq!(analysis.covered, 5);
+        assert_eq!(analysis.unassigned, vec![3..=3, 5..=7]);
+        assert_eq!(analysis.busiest, Some((8, 2)));
+        assert_eq!(analysis.redundant, vec![Elf { line: 2, position: 2 }]);
+        assert_eq!(
+            analysis.to_string(),
+            concat!(
+                "Sections covered: 5\n",
+                "Sections assigned to nobody: 3-3, 5-7\n",
+                "Busiest section: 8, assigned to 2 elves\n",
+                "Redundant elves (1): line 2 elf 2\n"
+            )
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use std::{io::BufRead, iter::Peekable, ops::RangeInclusive};
+
+use aoc_core::{AocError, Result};
+
+use crate::{Elf, Group};
+
+
+/// The sections assigned to one elf.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assignment {
+    pub elf: Elf,
+    pub sections: RangeInclusive<u32>
+}
+
+/// Reads `a-b,c-d` lines one assignment at a time, straight from the bytes of a reader.
+///
+/// Nothing is allocated per line: bytes are taken from the reader's own buffer as they
+/// are parsed, so inputs of any size stream through. Reading stops at the first error.
+pub struct AssignmentReader<R> {
+    reader: R,
+    line: usize,
+    column: usize,
+    // position of the next assignment in its group, from 1
+    position: usize,
+    done: bool
+}
+
+impl<R: BufRead> AssignmentReader<R> {
+    pub fn new(reader: R) -> Self {
+        AssignmentReader { reader, line: 1, column: 1, position: 1, done: false }
+    }
+
+    fn peek(&mut self) -> Result<Option<u8>> {
+        Ok(self.reader.fill_buf()?.first().copied())
+    }
+
+    fn bump(&mut self) {
+        self.reader.consume(1);
+        self.column += 1;
+    }
+
+    fn error(&self, column: usize, message: impl Into<String>) -> AocError {
+        AocError::parse(self.line, column, message)
+    }
+
+    /// An error for the byte about to be read, which is not the `expected` one.
+    fn unexpected(&mut self, column: usize, expected: &str) -> AocError {
+        let found = match self.peek() {
+            Ok(Some(b'\n' | b'\r') | None) => "the end of the line".to_owned(),
+            Ok(Some(byte)) => format!("`{}`", byte.escape_ascii()),
+            Err(error) => return error
+        };
+
+        self.error(column, format!("expected {expected}, found {found}"))
+    }
+
+    fn section(&mut self) -> Result<u32> {
+        let column = self.column;
+        let mut section: Option<u32> = None;
+
+        while let Some(byte) = self.peek()?.filter(u8::is_ascii_digit) {
+            section = section.unwrap_or(0)
+                .checked_mul(10)
+                .and_then(|section| section.checked_add(u32::from(byte - b'0')))
+                .map(Some)
+                .ok_or_else(|| self.error(column, "section number is too large"))?;
+            self.bump();
+        }
+
+        match section {
+            Some(section) => Ok(section),
+            None => Err(self.unexpected(column, "a section number"))
+        }
+    }
+
+    fn end_of_line(&mut self) -> Result<bool> {
+        match self.peek()? {
+            None => Ok(true),
+            Some(b'\n') => {
+                self.reader.consume(1);
+                Ok(true)
+            }
+            Some(b'\r') => {
+                self.bump();
+
+                match self.peek()? {
+                    Some(b'\n') => {
+                        self.reader.consume(1);
+                        Ok(true)
+                    }
+                    _ => Err(self.unexpected(self.column, "a line break"))
+                }
+            }
+            Some(_) => Ok(false)
+        }
+    }
+
+    fn assignment(&mut self) -> Result<Option<Assignment>> {
+        if self.position == 1 && self.peek()?.is_none() {
+            return Ok(None);
+        }
+
+        let column = self.column;
+        let start = self.section()?;
+
+        if self.peek()? != Some(b'-') {
+            return Err(self.unexpected(self.column, "`-` in a range like `2-4`"));
+        }
+
+        self.bump();
+        let end = self.section()?;
+
+        if start > end {
+            return Err(self.error(column, format!("range `{start}-{end}` ends before it starts")));
+        }
+
+        let sections = start..=end;
+        let elf = Elf { line: self.line, position: self.position };
+
+        if self.end_of_line()? {
+            if self.position < 2 {
+                return Err(self.error(1, "expected at least two ranges separated by `,`"));
+            }
+
+            self.line += 1;
+            self.column = 1;
+            self.position = 1;
+        } else if self.peek()? == Some(b',') {
+            self.bump();
+            self.position += 1;
+        } else {
+            return Err(self.unexpected(self.column, "`,` or the end of the line"));
+        }
+
+        Ok(Some(Assignment { elf, sections }))
+    }
+}
+
+impl<R: BufRead> Iterator for AssignmentReader<R> {
+    type Item = Result<Assignment>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let assignment = self.assignment().transpose();
+        self.done = !matches!(assignment, Some(Ok(_)));
+
+        assignment
+    }
+}
+
+/// Reads the assignments of one line at a time into a group, reusing its buffer from
+/// line to line.
+///
+/// A line that fails part way gives its error rather than the ranges read before it.
+pub struct GroupReader<R: BufRead> {
+    assignments: Peekable<AssignmentReader<R>>,
+    group: Group
+}
+
+impl<R: BufRead> GroupReader<R> {
+    pub fn new(reader: R) -> Self {
+        GroupReader { assignments: AssignmentReader::new(reader).peekable(), group: Vec::new() }
+    }
+
+    /// The next group, or `None` once the input is over.
+    pub fn next_group(&mut self) -> Result<Option<&[RangeInclusive<u32>]>> {
+        self.group.clear();
+
+        let Some(first) = self.assignments.next().transpose()? else {
+            return Ok(None);
+        };
+
+        let line = first.elf.line;
+        self.group.push(first.sections);
+
+        // an error ends the input, so it is taken along with the line it may have cut short
+        while let Some(next) = self.assignments.next_if(|next| next.as_ref().map_or(true, |next| next.elf.line == line)) {
+            self.group.push(next?.sections);
+        }
+
+        Ok(Some(&self.group))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_core::{AocError, Result};
+    use crate::{Assignment, AssignmentReader, Elf, GroupReader};
+
+    fn read(input: &str) -> Result<Vec<Assignment>> {
+        AssignmentReader::new(input.as_bytes()).collect()
+    }
+
+    #[test]
+    fn test_read() {
+        let assignments = read("2-4,6-8\r\n12-13,1-1,0-99\n").unwrap();
+
+        assert_eq!(assignments.len(), 5);
+        assert_eq!(assignments[1], Assignment { elf: Elf { line: 1, position: 2 }, sections: 6..=8 });
+        assert_eq!(assignments[4], Assignment { elf: Elf { line: 2, position: 3 }, sections: 0..=99 });
+
+        assert_eq!(read("2-4,6-8").unwrap().len(), 2);
+        assert!(read("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_read_groups() {
+        let mut groups = GroupReader::new("2-4,6-8\n1-1,2-2,3-3\n".as_bytes());
+
+        assert_eq!(groups.next_group().unwrap(), Some(&[2..=4, 6..=8][..]));
+        assert_eq!(groups.next_group().unwrap(), Some(&[1..=1, 2..=2, 3..=3][..]));
+        assert_eq!(groups.next_group().unwrap(), None);
+
+        // the ranges before the error are never handed out as a group of their own
+        let mut groups = GroupReader::new("2-4,6-8,x-9".as_bytes());
+        let error = groups.next_group().unwrap_err();
+        assert!(matches!(error, AocError::Parse { line: 1, column: 9, .. }), "{error}");
+    }
+
+    #[test]
+    fn test_read_split_buffer() {
+        // a reader with a tiny buffer splits numbers and line breaks across refills
+        let input = "123-4567,10-89\r\n11-12,13-14\n";
+        let reader = std::io::BufReader::with_capacity(3, input.as_bytes());
+        let sections: Vec<_> = AssignmentReader::new(reader).map(|assignment| assignment.unwrap().sections).collect();
+
+        assert_eq!(sections, vec![123..=4567, 10..=89, 11..=12, 13..=14]);
+    }
+
+    #[test]
+    fn test_read_errors() {
+        let error = read("2-4,6-8\n2-4,6-x").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 7: expected a section number, found `x`");
+
+        let error = read("2-4,68").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 7: expected `-` in a range like `2-4`, found the end of the line");
+
+        let error = read("2-4\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 1: expected at least two ranges separated by `,`");
+
+        let error = read("2-4;6-8").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 4: expected `,` or the end of the line, found `;`");
+
+        let error = read("2-4,6-8\n\n").unwrap_err();
+        assert!(matches!(error, AocError::Parse { line: 2, column: 1, .. }), "{error}");
+
+        let error = read("5-3,6-8").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 1: range `5-3` ends before it starts");
+
+        let error = read("2-4,6-99999999999").unwrap_err();
+        assert!(matches!(error, AocError::Parse { line: 1, column: 7, .. }), "{error}");
+    }
+}
@@ -0,0 +1,221 @@
+use std::{fmt::Write, ops::RangeInclusive};
+
+use aoc_core::{AocError, Result};
+
+use crate::{Group, any_contained, common_sections};
+
+
+/// How a group is drawn, after the answer it counts towards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Highlight {
+    /// A range of the group holds another one, counted by part 1
+    Contained,
+    /// Every range of the group shares a section, counted by part 2
+    Overlapping,
+    Apart
+}
+
+impl Highlight {
+    pub fn of(group: &[RangeInclusive<u32>]) -> Self {
+        if any_contained(group) {
+            Highlight::Contained
+        } else if common_sections(group).is_some() {
+            Highlight::Overlapping
+        } else {
+            Highlight::Apart
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Highlight::Contained => "contained",
+            Highlight::Overlapping => "overlapping",
+            Highlight::Apart => ""
+        }
+    }
+
+    fn ansi(self) -> &'static str {
+        match self {
+            Highlight::Contained => "\x1b[1;32m",
+            Highlight::Overlapping => "\x1b[33m",
+            Highlight::Apart => ""
+        }
+    }
+
+    fn fill(self) -> &'static str {
+        match self {
+            Highlight::Contained => "#2e7d32",
+            Highlight::Overlapping => "#f9a825",
+            Highlight::Apart => "#90a4ae"
+        }
+    }
+}
+
+/// The most sections drawn, beyond which a drawing would be too wide to be of any use.
+const MAX_SECTIONS: u64 = 1000;
+
+/// The sections shown for every group: from section 1, or lower, up to the last assigned one.
+fn axis(groups: &[Group]) -> Result<RangeInclusive<u32>> {
+    let assigned = groups.iter().flatten().filter(|range| !range.is_empty());
+    let start = assigned.clone().map(|range| *range.start()).min().unwrap_or(1).min(1);
+    let end = assigned.map(|range| *range.end()).max().unwrap_or(start);
+
+    let sections = u64::from(end) - u64::from(start) + 1;
+    if sections > MAX_SECTIONS {
+        return Err(AocError::solve(format!("cannot draw sections {start} to {end}, at most {MAX_SECTIONS} sections fit in a drawing")));
+    }
+
+    Ok(start..=end)
+}
+
+/// Draws every group like the puzzle does, one line per elf with the last digit of each
+/// of its sections, say `.234.....`, and the groups counted by either part labelled.
+///
+/// With `color` the bars of contained groups are also drawn in green and those of
+/// overlapping groups in yellow, using ANSI escapes.
+///
+/// Fails when the sections span more than a thousand columns.
+pub fn render_text(groups: &[Group], color: bool) -> Result<String> {
+    let axis = axis(groups)?;
+    let mut text = String::new();
+
+    for (index, group) in groups.iter().enumerate() {
+        if index > 0 {
+            text.push('\n');
+        }
+
+        let highlight = Highlight::of(group);
+
+        for (position, range) in group.iter().enumerate() {
+            let bar: String = axis.clone()
+                .map(|section| if range.contains(&section) { char::from_digit(section % 10, 10).unwrap_or('#') } else { '.' })
+                .collect();
+
+            let bar = if color && highlight != Highlight::Apart { format!("{}{bar}\x1b[0m", highlight.ansi()) } else { bar };
+            let label = if position == 0 { highlight.label() } else { "" };
+            let line = format!("{bar}  {:<9}{label}", format!("{}-{}", range.start(), range.end()));
+
+            text.push_str(line.trim_end());
+            text.push('\n');
+        }
+    }
+
+    Ok(text)
+}
+
+const CELL_WIDTH: u64 = 12;
+const ROW_HEIGHT: u64 = 16;
+const GROUP_GAP: u64 = 10;
+const MARGIN: u64 = 24;
+const LEGEND_WIDTH: u64 = 160;
+
+/// Draws every group as an SVG image, one bar per elf over a shared section axis,
+/// filled after how the group counts towards the answers.
+///
+/// Fails when the sections span more than a thousand columns, or the image would be
+/// too tall to measure.
+pub fn render_svg(groups: &[Group]) -> Result<String> {
+    let axis = axis(groups)?;
+    let offset = |section: u32| u64::from(section) - u64::from(*axis.start());
+    let sections = offset(*axis.end()) + 1;
+    let rows = groups.iter().map(|group| group.len() as u64).sum::<u64>();
+
+    let width = 2 * MARGIN + sections * CELL_WIDTH + LEGEND_WIDTH;
+    let height = rows.checked_mul(ROW_HEIGHT)
+        .and_then(|rows| rows.checked_add((groups.len() as u64).saturating_sub(1).checked_mul(GROUP_GAP)?))
+        .and_then(|body| body.checked_add(2 * MARGIN + ROW_HEIGHT))
+        .ok_or_else(|| AocError::solve("too many groups to draw"))?;
+
+    let mut svg = String::new();
+    let _ = writeln!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" font-family="monospace" font-size="10">"#);
+    let _ = writeln!(svg, r#"<rect width="{width}" height="{height}" fill="white"/>"#);
+
+    // a tick every section for narrow axes, every tenth otherwise
+    let tick: u32 = if sections <= 40 { 1 } else { 10 };
+    for section in axis.clone().filter(|section| section % tick == 0) {
+        let x = MARGIN + offset(section) * CELL_WIDTH;
+        let _ = writeln!(svg, r#"<text x="{}" y="{}" text-anchor="middle">{section}</text>"#, x + CELL_WIDTH / 2, MARGIN);
+        let _ = writeln!(svg, r##"<line x1="{x}" y1="{}" x2="{x}" y2="{}" stroke="#eceff1"/>"##, MARGIN + 4, height - MARGIN);
+    }
+
+    let mut y = MARGIN + ROW_HEIGHT;
+
+    for group in groups {
+        let highlight = Highlight::of(group);
+
+        for (position, range) in group.iter().enumerate() {
+            if !range.is_empty() {
+                let x = MARGIN + offset(*range.start()) * CELL_WIDTH;
+                let bar_width = (offset(*range.end()) - offset(*range.start()) + 1) * CELL_WIDTH;
+                let _ = writeln!(svg, r#"<rect x="{x}" y="{}" width="{bar_width}" height="{}" rx="3" fill="{}"/>"#, y + 2, ROW_HEIGHT - 4, highlight.fill());
+            }
+
+            let label = if position == 0 { highlight.label() } else { "" };
+            let _ = writeln!(
+                svg,
+                r#"<text x="{}" y="{}">{}-{} {label}</text>"#,
+                MARGIN + sections * CELL_WIDTH + 8,
+                y + ROW_HEIGHT - 4,
+                range.start(),
+                range.end()
+            );
+
+            y += ROW_HEIGHT;
+        }
+
+        y += GROUP_GAP;
+    }
+
+    svg.push_str("</svg>\n");
+    Ok(svg)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Highlight, render_svg, render_text};
+
+    #[test]
+    fn test_render_text() {
+        let groups = vec![vec![2..=4, 6..=8], vec![5..=7, 7..=9], vec![2..=8, 3..=7]];
+
+        assert_eq!(
+            render_text(&groups, false).unwrap(),
+            concat!(
+                ".234.....  2-4\n",
+                ".....678.  6-8\n",
+                "\n",
+                "....567..  5-7      overlapping\n",
+                "......789  7-9\n",
+                "\n",
+                ".2345678.  2-8      contained\n",
+                "..34567..  3-7\n"
+            )
+        );
+
+        assert!(render_text(&groups, true).unwrap().contains("\x1b[1;32m.2345678.\x1b[0m  2-8"));
+    }
+
+    #[test]
+    fn test_render_svg() {
+        let groups = vec![vec![2..=4, 6..=8], vec![2..=8, 3..=7, 1..=1]];
+        let svg = render_svg(&groups).unwrap();
+
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches(r#"rx="3""#).count(), 5);
+        assert_eq!(svg.matches(Highlight::Contained.fill()).count(), 3);
+    }
+
+    #[test]
+    fn test_render_wide_axis() {
+        let groups = vec![vec![1..=1, 4_000_000_000..=4_000_000_000]];
+        assert!(render_svg(&groups).is_err());
+        assert!(render_text(&groups, false).is_err());
+
+        let groups = vec![vec![0..=u32::MAX, 2..=4]];
+        assert!(render_svg(&groups).is_err());
+
+        let groups = vec![vec![1..=900, 1000..=1000]];
+        assert!(render_svg(&groups).is_ok());
+    }
+}
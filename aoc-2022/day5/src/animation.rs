@@ -0,0 +1,219 @@
+use std::{io::{self, Write}, time::Duration};
+
+use aoc_core::{AocError, Result};
+use crossterm::{cursor, event::{self, Event, KeyCode, KeyEventKind, KeyModifiers}, execute, queue, style::{Print, PrintStyledContent, Stylize}, terminal};
+
+use crate::{Crane, Instruction, Stacks, legal_steps, stack_numbers};
+
+
+/// How many steps apart the snapshots of a replay are.
+const CHECKPOINT_INTERVAL: usize = 256;
+
+
+/// The stacks after any number of legal instructions, worked out as they are shown.
+///
+/// The stacks and the crane are saved every `CHECKPOINT_INTERVAL` steps, so stepping back
+/// only replays the instructions since the closest snapshot instead of the whole procedure.
+struct Replay<'a, C> {
+    instructions: &'a [Instruction],
+    checkpoints: Vec<(Stacks, C)>,
+    stacks: Stacks,
+    crane: C,
+    // how many instructions `stacks` went through
+    step: usize
+}
+
+
+impl<'a, C: Crane + Clone> Replay<'a, C> {
+    fn new(crane: C, stacks: Stacks, instructions: &'a [Instruction]) -> Self {
+        Replay { instructions, checkpoints: vec![(stacks.clone(), crane.clone())], stacks, crane, step: 0 }
+    }
+
+
+    /// Brings the stacks to right after the first `step` instructions, which must all be legal.
+    fn seek(&mut self, step: usize) {
+        if step < self.step {
+            let checkpoint = step / CHECKPOINT_INTERVAL;
+            (self.stacks, self.crane) = self.checkpoints[checkpoint].clone();
+            self.step = checkpoint * CHECKPOINT_INTERVAL;
+        }
+
+        while self.step < step {
+            let instruction = &self.instructions[self.step];
+            self.crane.lift(&mut self.stacks, instruction.src, instruction.destination, instruction.quantity);
+            self.step += 1;
+
+            if self.step.is_multiple_of(CHECKPOINT_INTERVAL) && self.step / CHECKPOINT_INTERVAL == self.checkpoints.len() {
+                self.checkpoints.push((self.stacks.clone(), self.crane.clone()));
+            }
+        }
+    }
+
+
+    /// The instruction that led to the current stacks, if any.
+    fn last_instruction(&self) -> Option<&'a Instruction> {
+        self.step.checked_sub(1).map(|index| &self.instructions[index])
+    }
+}
+
+
+/// Puts the terminal back the way it was, even when drawing fails half way.
+struct Screen;
+
+
+impl Screen {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+
+        Ok(Screen)
+    }
+}
+
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+
+fn draw<C: Crane + Clone>(out: &mut impl Write, replay: &Replay<'_, C>, steps: usize, failure: Option<&AocError>, playing: bool) -> io::Result<()> {
+    let stacks = &replay.stacks;
+    let instruction = replay.last_instruction();
+
+    queue!(out, terminal::Clear(terminal::ClearType::All), cursor::MoveTo(0, 0))?;
+
+    match instruction {
+        Some(instruction) => queue!(out, Print(format!(
+            "Step {}/{steps}: move {} from {} to {}\r\n\r\n",
+            replay.step,
+            instruction.quantity,
+            instruction.src + 1,
+            instruction.destination + 1
+        )))?,
+        None => queue!(out, Print(format!("Step 0/{steps}: starting stacks\r\n\r\n")))?
+    }
+
+    let height = stacks.iter().map(<[_]>::len).max().unwrap_or(0);
+
+    for level in (0..height).rev() {
+        for (stack, crates) in stacks.iter().enumerate() {
+            if stack > 0 {
+                queue!(out, Print(" "))?;
+            }
+
+            let moved = instruction.is_some_and(|instruction| instruction.destination == stack && level + instruction.quantity >= crates.len());
+
+            match crates.get(level) {
+                Some(top_crate) if moved => queue!(out, PrintStyledContent(format!("[{}]", top_crate.0).yellow().bold()))?,
+                Some(top_crate) => queue!(out, Print(format!("[{}]", top_crate.0)))?,
+                None => queue!(out, Print("   "))?
+            }
+        }
+
+        queue!(out, Print("\r\n"))?;
+    }
+
+    queue!(out, Print(stack_numbers(stacks.len())), Print("\r\n\r\n"))?;
+
+    if let Some(failure) = failure.filter(|_| replay.step == steps) {
+        queue!(out, PrintStyledContent(format!("Stopped: {failure}").red()), Print("\r\n\r\n"))?;
+    }
+
+    let play = if playing { "pause" } else { "play" };
+    queue!(out, Print(format!("←/→ step   home/end first/last   space {play}   q quit")))?;
+
+    out.flush()
+}
+
+
+/// Applies the instructions through `crane` one at a time in the terminal, redrawing the
+/// stacks after each one with the crates that just moved highlighted.
+///
+/// Steps play every `delay` and can be paused and stepped through in either direction.
+/// An impossible instruction ends the animation on the stacks right before it, and is
+/// returned once the animation is closed.
+///
+/// The stacks are only worked out for the steps shown, so long procedures start right away.
+pub fn animate(crane: impl Crane + Clone, stacks: Stacks, instructions: &[Instruction], delay: Duration) -> Result<()> {
+    let (steps, failure) = legal_steps(&stacks, instructions);
+    let mut replay = Replay::new(crane, stacks, instructions);
+
+    let screen = Screen::enter()?;
+    let mut out = io::stdout();
+    let mut index = 0;
+    let mut playing = true;
+
+    loop {
+        playing &= index < steps;
+        replay.seek(index);
+        draw(&mut out, &replay, steps, failure.as_ref(), playing)?;
+
+        if playing && !event::poll(delay)? {
+            index += 1;
+            continue;
+        }
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => break,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => break,
+            KeyCode::Char(' ') => playing = !playing,
+            KeyCode::Right | KeyCode::Char('l') => {
+                playing = false;
+                index = (index + 1).min(steps);
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                playing = false;
+                index = index.saturating_sub(1);
+            }
+            KeyCode::Home => index = 0,
+            KeyCode::End => index = steps,
+            _ => {}
+        }
+    }
+
+    drop(screen);
+
+    failure.map_or(Ok(()), Err)
+}
+
+
+#[cfg(test)]
+mod test {
+    use crate::{Crane, CrateMover9000, parse_input};
+    use super::{CHECKPOINT_INTERVAL, Replay};
+
+    #[test]
+    fn test_replay() {
+        let mut input = "[A]    \n[B] [C]\n 1   2 \n\n".to_owned();
+        for step in 0..CHECKPOINT_INTERVAL * 2 + 10 {
+            input += if step % 3 == 2 { "move 2 from 2 to 1\n" } else { "move 1 from 1 to 2\n" };
+        }
+
+        let (stacks, instructions) = parse_input(&input).unwrap();
+        let mut replay = Replay::new(CrateMover9000, stacks.clone(), &instructions);
+
+        for step in [CHECKPOINT_INTERVAL * 2 + 5, 3, CHECKPOINT_INTERVAL + 1, 0, instructions.len()] {
+            replay.seek(step);
+
+            let mut expected = stacks.clone();
+            for instruction in &instructions[..step] {
+                CrateMover9000.lift(&mut expected, instruction.src, instruction.destination, instruction.quantity);
+            }
+
+            assert_eq!(replay.stacks, expected, "step {step}");
+        }
+
+        assert_eq!(replay.checkpoints.len(), 3);
+    }
+}
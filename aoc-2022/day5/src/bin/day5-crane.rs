@@ -0,0 +1,75 @@
+//! Runs a day 5 rearrangement procedure with any crane model.
+//!
+//! `day5-crane <input> [--crane 9000|9001|flipping|<capacity>] [--animate] [--delay <ms>]`
+//! prints the crates left on top of the stacks, or with `--animate` plays the procedure
+//! step by step in the terminal.
+
+use std::{env, error::Error, fs, num::NonZeroUsize, process::ExitCode, time::Duration};
+
+use aoc_core::Solution;
+use day5::{CapacityLimited, Crane, CrateMover9000, CrateMover9001, Day5, FlippingCrane, animate, simulate};
+
+
+struct Options {
+    input: String,
+    animate: bool,
+    delay: Duration
+}
+
+
+fn run_with(mut crane: impl Crane + Clone, options: &Options) -> Result<(), Box<dyn Error>> {
+    let (stacks, instructions) = Day5.parse(&fs::read_to_string(&options.input)?)?;
+
+    if options.animate {
+        animate(crane, stacks, &instructions, options.delay)?;
+    } else {
+        println!("{}", simulate(&mut crane, stacks, &instructions)?);
+    }
+
+    Ok(())
+}
+
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let mut input = None;
+    let mut crane = "9000".to_owned();
+    let mut animate = false;
+    let mut delay = Duration::from_millis(500);
+
+    let mut arguments = env::args().skip(1);
+
+    while let Some(argument) = arguments.next() {
+        match argument.as_str() {
+            "--animate" => animate = true,
+            "--crane" => crane = arguments.next().ok_or("--crane needs a model")?,
+            "--delay" => delay = Duration::from_millis(arguments.next().ok_or("--delay needs milliseconds")?.parse()?),
+            _ if input.is_none() => input = Some(argument),
+            _ => return Err(format!("unexpected argument `{argument}`").into())
+        }
+    }
+
+    let input = input.ok_or("usage: day5-crane <input> [--crane 9000|9001|flipping|<capacity>] [--animate] [--delay <ms>]")?;
+    let options = Options { input, animate, delay };
+
+    match crane.as_str() {
+        "9000" => run_with(CrateMover9000, &options),
+        "9001" => run_with(CrateMover9001, &options),
+        "flipping" => run_with(FlippingCrane, &options),
+        capacity => {
+            let capacity: NonZeroUsize = capacity.parse()
+                .map_err(|_| format!("unknown crane `{capacity}`, expected 9000, 9001, flipping or a capacity"))?;
+            run_with(CapacityLimited { capacity }, &options)
+        }
+    }
+}
+
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,484 @@
+use std::{fmt::Debug, num::NonZeroUsize, vec};
+use aoc_core::{Answer, AocError, ParseResult, Result, Solution, parse_input_line, token};
+use aoc_utils::number;
+use nom::{Parser, branch::alt, character::complete::{one_of, space0}, combinator::{cut, map, map_res, opt, value}, error::context, multi::many1, sequence::{delimited, preceded}};
+
+#[cfg(feature = "animate")]
+mod animation;
+mod stacks;
+
+#[cfg(feature = "animate")]
+pub use animation::animate;
+pub use stacks::Stacks;
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crate(char);
+
+
+#[derive(Debug)]
+pub struct Instruction {
+    // line of the input the instruction was read from
+    line: usize,
+    quantity: usize,
+    src: usize,
+    destination: usize
+}
+
+
+fn parse_crate(input: &str) -> ParseResult<'_, Crate> {
+    let crate_parser = delimited(token("["), context("a crate letter", one_of("ABCDEFGHIJKLMNOPQRSTUVWXYZ")), token("]"));
+    map(crate_parser, Crate).parse(input)
+}
+
+
+fn parse_hole(input: &str) -> ParseResult<'_, ()> {
+    value((), token("   ")).parse(input)
+}
+
+
+fn parse_crate_or_hole(input: &str) -> ParseResult<'_, Option<Crate>> {
+    alt((map(parse_crate, Some), map(parse_hole, |_| None))).parse(input)
+}
+
+
+fn parse_line_of_crates(input: &str) -> ParseResult<'_, Vec<Option<Crate>>> {
+    let (mut input, maybe_crate) = parse_crate_or_hole(input)?;
+    let mut result = vec![maybe_crate];
+
+    loop {
+        let (rest_input, maybe_crate) = opt(preceded(token(" "), cut(parse_crate_or_hole))).parse(input)?;
+        match maybe_crate {
+            Some(maybe_crate) => result.push(maybe_crate),
+            None => break,
+        }
+
+        input = rest_input;
+    }
+
+    Ok((input, result))
+}
+
+
+fn parse_pile_number(input: &str) -> ParseResult<'_, usize> {
+    map_res(number, |number: usize| number.checked_sub(1).ok_or("piles are numbered from 1")).parse(input)
+}
+
+
+fn parse_pile_numbers(input: &str) -> ParseResult<'_, Vec<usize>> {
+    many1(delimited(space0, number::<usize, _>, space0)).parse(input)
+}
+
+
+fn parse_instruction(input: &str, line: usize) -> ParseResult<'_, Instruction> {
+    map(
+        (
+            preceded(token("move "), number),
+            preceded(token(" from "), parse_pile_number),
+            preceded(token(" to "), parse_pile_number),
+        ),
+        |(quantity, src, destination)| Instruction {
+            line,
+            quantity,
+            src,
+            destination,
+        },
+    )
+    .parse(input)
+}
+
+
+fn transpose_reversed<T>(matrix: Vec<Vec<Option<T>>>, piles: usize) -> Vec<Vec<T>> {
+    let n = matrix.iter().map(|row| row.len()).max().unwrap_or(0).max(piles);
+
+    let mut iters: Vec<_> = matrix.into_iter().map(|row| row.into_iter()).collect();
+
+    (0..n)
+        .map(|_| {
+            iters
+                .iter_mut()
+                .rev()
+                .filter_map(|it| it.next().flatten())
+                .collect::<Vec<T>>()
+        })
+        .collect()
+}
+
+
+/// The footer of a drawing, the number of every stack centred below it.
+fn stack_numbers(stacks: usize) -> String {
+    (1..=stacks).map(|stack| format!(" {stack} ")).collect::<Vec<_>>().join(" ")
+}
+
+
+/// Draws the stacks the way the puzzle input does, `[A]` crates in columns over the
+/// stack numbers, with every line ending in a line break.
+///
+/// Parsing the drawing gives back the same stacks. Columns stay aligned for up to 9
+/// stacks, like in the puzzle.
+pub fn render_stacks(stacks: &Stacks) -> String {
+    let height = stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+    let mut drawing = String::new();
+
+    for level in (0..height).rev() {
+        let row: Vec<String> = stacks.iter()
+            .map(|stack| stack.get(level).map_or_else(|| "   ".to_owned(), |top_crate| format!("[{}]", top_crate.0)))
+            .collect();
+
+        drawing.push_str(&row.join(" "));
+        drawing.push('\n');
+    }
+
+    drawing.push_str(&stack_numbers(stacks.len()));
+    drawing.push('\n');
+
+    drawing
+}
+
+
+fn top_crates(stacks: &Stacks) -> Result<String> {
+    stacks.iter()
+        .enumerate()
+        .map(|(index, stack)| stack
+            .last()
+            .map(|top_crate| top_crate.0)
+            .ok_or_else(|| AocError::solve(format!("stack {} ends up empty", index + 1)))
+        )
+        .collect()
+}
+
+
+/// A crane model, deciding in which order the crates of a move land on their destination.
+///
+/// Cranes only ever see legal moves: `source` and `destination` are two different stacks
+/// and `quantity` is never more than the crates of `source`.
+pub trait Crane {
+    fn lift(&mut self, stacks: &mut Stacks, source: usize, destination: usize, quantity: usize);
+}
+
+
+/// Moves crates one at a time, reversing their order.
+#[derive(Clone)]
+pub struct CrateMover9000;
+
+
+impl Crane for CrateMover9000 {
+    fn lift(&mut self, stacks: &mut Stacks, source: usize, destination: usize, quantity: usize) {
+        stacks.move_one_by_one(source, destination, quantity);
+    }
+}
+
+
+/// Moves all the crates at once, keeping their order.
+#[derive(Clone)]
+pub struct CrateMover9001;
+
+
+impl Crane for CrateMover9001 {
+    fn lift(&mut self, stacks: &mut Stacks, source: usize, destination: usize, quantity: usize) {
+        stacks.move_block(source, destination, quantity);
+    }
+}
+
+
+/// Moves at most `capacity` crates at once, keeping the order of each lift.
+#[derive(Clone)]
+pub struct CapacityLimited {
+    pub capacity: NonZeroUsize
+}
+
+
+impl Crane for CapacityLimited {
+    fn lift(&mut self, stacks: &mut Stacks, source: usize, destination: usize, quantity: usize) {
+        let mut remaining = quantity;
+
+        while remaining > 0 {
+            let lift = remaining.min(self.capacity.get());
+            stacks.move_block(source, destination, lift);
+            remaining -= lift;
+        }
+    }
+}
+
+
+/// Moves crates one at a time like a CrateMover 9000, but flips every other crate of a
+/// lift: the second, fourth, ... crate is slid under the crate lifted right before it
+/// instead of being put on top.
+#[derive(Clone)]
+pub struct FlippingCrane;
+
+
+impl Crane for FlippingCrane {
+    fn lift(&mut self, stacks: &mut Stacks, source: usize, destination: usize, quantity: usize) {
+        let (source, destination) = stacks.get_two_mut(source, destination)
+            .expect("crates are moved between two different existing stacks");
+
+        for (index, lifted) in source.drain(source.len() - quantity..).rev().enumerate() {
+            if index.is_multiple_of(2) {
+                destination.push(lifted);
+            } else {
+                destination.insert(destination.len() - 1, lifted);
+            }
+        }
+    }
+}
+
+
+/// Whether the instruction can be carried out on stacks of these heights.
+fn check(instruction: &Instruction, heights: &[usize]) -> Result<()> {
+    let illegal = |reason: String| {
+        let heights: Vec<String> = heights.iter().map(usize::to_string).collect();
+        AocError::solve(format!("line {}: {reason}, the stacks are {} crates high", instruction.line, heights.join(", ")))
+    };
+
+    for stack in [instruction.src, instruction.destination] {
+        if stack >= heights.len() {
+            return Err(illegal(format!("there is no stack {}", stack + 1)));
+        }
+    }
+
+    if instruction.src == instruction.destination {
+        return Err(illegal(format!("cannot move crates from stack {} onto itself", instruction.src + 1)));
+    }
+
+    if heights[instruction.src] < instruction.quantity {
+        return Err(illegal(format!(
+            "cannot move {} crates from stack {} holding {}",
+            instruction.quantity,
+            instruction.src + 1,
+            heights[instruction.src]
+        )));
+    }
+
+    Ok(())
+}
+
+
+/// Finds the first instruction that cannot be carried out, whatever the crane.
+///
+/// Cranes only change the order of the crates they move, so following how high every
+/// stack is is enough to know whether each move is possible.
+pub fn validate(stacks: &Stacks, instructions: &[Instruction]) -> Result<()> {
+    match legal_steps(stacks, instructions) {
+        (_, Some(error)) => Err(error),
+        (_, None) => Ok(())
+    }
+}
+
+
+/// How many instructions can be carried out before the first impossible one, and why
+/// that one is impossible.
+fn legal_steps(stacks: &Stacks, instructions: &[Instruction]) -> (usize, Option<AocError>) {
+    let mut heights = stacks.heights();
+
+    for (index, instruction) in instructions.iter().enumerate() {
+        if let Err(error) = check(instruction, &heights) {
+            return (index, Some(error));
+        }
+
+        heights[instruction.src] -= instruction.quantity;
+        heights[instruction.destination] += instruction.quantity;
+    }
+
+    (instructions.len(), None)
+}
+
+
+/// Applies every instruction through `crane` and reads the crates left on top of the stacks.
+pub fn simulate(crane: &mut impl Crane, mut stacks: Stacks, instructions: &[Instruction]) -> Result<String> {
+    validate(&stacks, instructions)?;
+
+    // every move is known to be legal now, no need to check them again one by one
+    for instruction in instructions {
+        crane.lift(&mut stacks, instruction.src, instruction.destination, instruction.quantity);
+    }
+
+    top_crates(&stacks)
+}
+
+
+fn solve_part_1(stacks: Stacks, instructions: &[Instruction]) -> Result<String> {
+    simulate(&mut CrateMover9000, stacks, instructions)
+}
+
+
+fn solve_part_2(stacks: Stacks, instructions: &[Instruction]) -> Result<String> {
+    simulate(&mut CrateMover9001, stacks, instructions)
+}
+
+
+fn parse_input(input: &str) -> Result<(Stacks, Vec<Instruction>)> {
+    let mut lines = input.lines().enumerate();
+    let mut crates_matrix = Vec::new();
+    let piles;
+
+    loop {
+        let (index, line) = lines.next()
+            .ok_or_else(|| AocError::parse(input.lines().count() + 1, 1, "expected the stack numbers below the drawing"))?;
+
+        let crate_error = match parse_input_line(parse_line_of_crates, line, index + 1) {
+            Ok(crates_vector) => {
+                crates_matrix.push(crates_vector);
+                continue;
+            }
+            Err(error) => error
+        };
+
+        // the drawing ends with the stack numbers, anything else is a malformed row of crates
+        match parse_input_line(parse_pile_numbers, line, index + 1) {
+            Ok(pile_numbers) => {
+                piles = pile_numbers.len();
+                break;
+            }
+            Err(_) => return Err(crate_error)
+        }
+    }
+
+    let stacks = Stacks::new(transpose_reversed(crates_matrix, piles));
+
+    match lines.next() {
+        Some((_, "")) => {}
+        Some((index, _)) => return Err(AocError::parse(index + 1, 1, "expected an empty line after the stack numbers")),
+        None => return Err(AocError::parse(input.lines().count() + 1, 1, "expected an empty line after the stack numbers"))
+    }
+
+    let instructions = lines
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(index, line)| parse_input_line(|input| parse_instruction(input, index + 1), line, index + 1))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((stacks, instructions))
+}
+
+
+#[cfg(feature = "embedded")]
+pub const INPUT: &str = include_str!("input.txt");
+
+
+pub struct Day5;
+
+
+impl Solution for Day5 {
+    type Input = (Stacks, Vec<Instruction>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, (stacks, instructions): &Self::Input) -> Result<Answer> {
+        solve_part_1(stacks.clone(), instructions).map(Answer::from)
+    }
+
+    fn part2(&self, (stacks, instructions): &Self::Input) -> Result<Answer> {
+        solve_part_2(stacks.clone(), instructions).map(Answer::from)
+    }
+
+    #[cfg(feature = "embedded")]
+    fn embedded_input(&self) -> Option<&'static str> {
+        Some(INPUT)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use std::num::NonZeroUsize;
+
+    use aoc_core::AocError;
+    use crate::{CapacityLimited, Crane, Crate, CrateMover9000, FlippingCrane, Stacks, parse_input, render_stacks, simulate, solve_part_1, solve_part_2, validate};
+
+    const EXAMPLE: &str = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2\n";
+
+
+    #[test]
+    fn test_example() {
+        let (stacks, instructions) = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(solve_part_1(stacks.clone(), &instructions).unwrap(), "CMZ");
+        assert_eq!(solve_part_2(stacks, &instructions).unwrap(), "MCD");
+    }
+
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input(&EXAMPLE.replace("move 3 from 1", "move 3 frm 1")).unwrap_err();
+        assert_eq!(error.to_string(), "line 7, column 7: expected ` from `");
+
+        let error = parse_input(&EXAMPLE.replace("from 2 to 1\nmove 3", "from 0 to 1\nmove 3")).unwrap_err();
+        assert_eq!(error.to_string(), "line 6, column 13: piles are numbered from 1");
+
+        let error = parse_input(&EXAMPLE.replace("[M]", "[m]")).unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 6: expected a crate letter");
+
+        let error = parse_input(&EXAMPLE.replace("move 2 from 2 to 1", "move 2 from 2 to 1 now")).unwrap_err();
+        assert_eq!(error.to_string(), "line 8, column 19: expected end of line");
+
+        let error = parse_input(&EXAMPLE.replace(" 3 \n\n", " 3 \nmove 1 from 2 to 1\n")).unwrap_err();
+        assert!(matches!(error, AocError::Parse { line: 5, column: 1, .. }), "{error}");
+    }
+
+
+    #[test]
+    fn test_illegal_moves() {
+        let (stacks, instructions) = parse_input(&EXAMPLE.replace("move 3 from 1 to 3", "move 4 from 1 to 3")).unwrap();
+
+        assert!(matches!(solve_part_1(stacks.clone(), &instructions), Err(AocError::Solve(_))));
+        assert!(matches!(solve_part_2(stacks.clone(), &instructions), Err(AocError::Solve(_))));
+
+        let error = validate(&stacks, &instructions).unwrap_err();
+        assert_eq!(error.to_string(), "line 7: cannot move 4 crates from stack 1 holding 3, the stacks are 3, 2, 1 crates high");
+
+        let (stacks, instructions) = parse_input(&EXAMPLE.replace("move 1 from 1 to 2", "move 1 from 1 to 4")).unwrap();
+        let error = validate(&stacks, &instructions).unwrap_err();
+        assert_eq!(error.to_string(), "line 9: there is no stack 4, the stacks are 2, 0, 4 crates high");
+
+        let (stacks, instructions) = parse_input(&EXAMPLE.replace("move 2 from 2 to 1", "move 2 from 3 to 3")).unwrap();
+        let error = validate(&stacks, &instructions).unwrap_err();
+        assert_eq!(error.to_string(), "line 8: cannot move crates from stack 3 onto itself, the stacks are 0, 2, 4 crates high");
+    }
+
+
+    #[test]
+    fn test_other_cranes() {
+        let (stacks, instructions) = parse_input(EXAMPLE).unwrap();
+
+        let mut single = CapacityLimited { capacity: NonZeroUsize::MIN };
+        assert_eq!(simulate(&mut single, stacks.clone(), &instructions).unwrap(), "CMZ");
+
+        let mut large = CapacityLimited { capacity: NonZeroUsize::new(3).unwrap() };
+        assert_eq!(simulate(&mut large, stacks.clone(), &instructions).unwrap(), "MCD");
+
+        let mut pairs = CapacityLimited { capacity: NonZeroUsize::new(2).unwrap() };
+        assert_eq!(simulate(&mut pairs, stacks.clone(), &instructions).unwrap(), "MCZ");
+
+        assert_eq!(simulate(&mut FlippingCrane, stacks, &instructions).unwrap(), "MCZ");
+
+        // the top crate `E` lands first, `D` is slid under it, `C` goes on top and so on
+        let mut stacks = Stacks::new(vec!["ABCDE".chars().map(Crate).collect(), vec![Crate('Z')]]);
+        FlippingCrane.lift(&mut stacks, 0, 1, 5);
+        assert_eq!(stacks, Stacks::new(vec![Vec::new(), "ZDEBCA".chars().map(Crate).collect()]));
+    }
+
+
+    #[test]
+    fn test_render_stacks() {
+        let (mut stacks, instructions) = parse_input(EXAMPLE).unwrap();
+        let drawing = render_stacks(&stacks);
+
+        assert_eq!(drawing, "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n");
+
+        for instruction in &instructions {
+            CrateMover9000.lift(&mut stacks, instruction.src, instruction.destination, instruction.quantity);
+
+            let (parsed, _) = parse_input(&(render_stacks(&stacks) + "\n")).unwrap();
+            assert_eq!(parsed, stacks);
+        }
+
+        assert_eq!(render_stacks(&stacks), "        [Z]\n        [N]\n        [D]\n[C] [M] [P]\n 1   2   3 \n");
+
+        // stacks without crates are only drawn in the footer
+        stacks.clear();
+        let (parsed, _) = parse_input(&(render_stacks(&stacks) + "\n")).unwrap();
+        assert_eq!(parsed.len(), 3);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::Crate;
+
+
+/// The stacks of crates, each one from its bottom crate up.
+///
+/// Moves take the crates off the top of one stack and put them on another in a single
+/// `split_off` and `extend`, so they cost the crates moved whatever the stack heights.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stacks {
+    stacks: Vec<Vec<Crate>>
+}
+
+
+impl Stacks {
+    pub fn new(stacks: Vec<Vec<Crate>>) -> Self {
+        Stacks { stacks }
+    }
+
+
+    pub fn len(&self) -> usize {
+        self.stacks.len()
+    }
+
+
+    pub fn is_empty(&self) -> bool {
+        self.stacks.is_empty()
+    }
+
+
+    pub fn get(&self, stack: usize) -> Option<&[Crate]> {
+        self.stacks.get(stack).map(Vec::as_slice)
+    }
+
+
+    pub fn iter(&self) -> impl Iterator<Item = &[Crate]> {
+        self.stacks.iter().map(Vec::as_slice)
+    }
+
+
+    /// How many crates every stack holds.
+    pub fn heights(&self) -> Vec<usize> {
+        self.stacks.iter().map(Vec::len).collect()
+    }
+
+
+    /// Empties every stack, keeping the stacks themselves.
+    pub fn clear(&mut self) {
+        self.stacks.iter_mut().for_each(Vec::clear);
+    }
+
+
+    /// Two different stacks borrowed mutably at the same time, or `None` when they are
+    /// the same stack or either one does not exist.
+    pub fn get_two_mut(&mut self, first: usize, second: usize) -> Option<(&mut Vec<Crate>, &mut Vec<Crate>)> {
+        if first == second || first.max(second) >= self.stacks.len() {
+            return None;
+        }
+
+        let (low, high) = self.stacks.split_at_mut(first.max(second));
+        let (low, high) = (&mut low[first.min(second)], &mut high[0]);
+
+        Some(if first < second { (low, high) } else { (high, low) })
+    }
+
+
+    /// Takes the top `quantity` crates off `source`, bottom one first, along with the
+    /// `destination` they go to.
+    fn lift(&mut self, source: usize, destination: usize, quantity: usize) -> (Vec<Crate>, &mut Vec<Crate>) {
+        let (source, destination) = self.get_two_mut(source, destination)
+            .expect("crates are moved between two different existing stacks");
+
+        assert!(quantity <= source.len(), "cannot move {quantity} crates from a stack holding {}", source.len());
+
+        (source.split_off(source.len() - quantity), destination)
+    }
+
+
+    /// Moves the top `quantity` crates of `source` one at a time, so they land on
+    /// `destination` in reverse order.
+    ///
+    /// # Panics
+    ///
+    /// When the stacks are the same or do not exist, or `source` holds fewer crates.
+    pub fn move_one_by_one(&mut self, source: usize, destination: usize, quantity: usize) {
+        let (lifted, destination) = self.lift(source, destination, quantity);
+        destination.extend(lifted.into_iter().rev());
+    }
+
+
+    /// Moves the top `quantity` crates of `source` all at once, so they keep their order.
+    ///
+    /// # Panics
+    ///
+    /// When the stacks are the same or do not exist, or `source` holds fewer crates.
+    pub fn move_block(&mut self, source: usize, destination: usize, quantity: usize) {
+        let (lifted, destination) = self.lift(source, destination, quantity);
+        destination.extend(lifted);
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use crate::{Crate, Stacks};
+
+    fn stacks(stacks: &[&str]) -> Stacks {
+        Stacks::new(stacks.iter().map(|stack| stack.chars().map(Crate).collect()).collect())
+    }
+
+
+    #[test]
+    fn test_get_two_mut() {
+        let mut crates = stacks(&["AB", "C", ""]);
+
+        let (first, second) = crates.get_two_mut(1, 0).unwrap();
+        assert_eq!((first.len(), second.len()), (1, 2));
+
+        assert!(crates.get_two_mut(1, 1).is_none());
+        assert!(crates.get_two_mut(0, 3).is_none());
+    }
+
+
+    #[test]
+    fn test_moves() {
+        let mut crates = stacks(&["ABC", "D", ""]);
+
+        crates.move_one_by_one(0, 2, 2);
+        assert_eq!(crates, stacks(&["A", "D", "CB"]));
+
+        crates.move_block(2, 1, 2);
+        assert_eq!(crates, stacks(&["A", "DCB", ""]));
+
+        crates.move_block(1, 0, 0);
+        assert_eq!(crates.heights(), vec![1, 3, 0]);
+    }
+
+
+    #[test]
+    #[should_panic(expected = "cannot move 2 crates from a stack holding 1")]
+    fn test_move_too_many() {
+        stacks(&["A", ""]).move_block(0, 1, 2);
+    }
+}
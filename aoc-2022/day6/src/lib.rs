@@ -0,0 +1,58 @@
+use std::collections::HashSet;
+use aoc_core::{Answer, AocError, Result, Solution};
+
+
+#[cfg(feature = "embedded")]
+pub const INPUT: &str = include_str!("input.txt");
+
+
+pub struct Day6;
+
+
+impl Solution for Day6 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.trim_end().to_owned())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        solve_part(input, 4)
+            .map(Answer::from)
+            .ok_or_else(|| AocError::solve("datastream has no start-of-packet marker"))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        solve_part(input, 14)
+            .map(Answer::from)
+            .ok_or_else(|| AocError::solve("datastream has no start-of-message marker"))
+    }
+
+    #[cfg(feature = "embedded")]
+    fn embedded_input(&self) -> Option<&'static str> {
+        Some(INPUT)
+    }
+}
+
+
+fn solve_part(input: &str, sequence_size: usize) -> Option<usize> {
+    input.as_bytes()
+        .windows(sequence_size)
+        .position(|window| window.iter().collect::<HashSet<_>>().len() == sequence_size)
+        .map(|position| position + sequence_size)
+}
+
+
+#[cfg(test)]
+mod test {
+    use crate::solve_part;
+
+    #[test]
+    fn test_find_marker() {
+        assert_eq!(Some(7), solve_part("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 4));
+        assert_eq!(Some(5), solve_part("bvwbjplbgvbhsrlpgdmjqwftvncz", 4));
+        assert_eq!(Some(6), solve_part("nppdvjthqldpwncqszvftbrmjlhg", 4));
+        assert_eq!(Some(10), solve_part("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 4));
+        assert_eq!(Some(11), solve_part("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 4));
+    }
+}
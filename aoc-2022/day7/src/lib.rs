@@ -1,6 +1,8 @@
 use core::fmt;
 use std::{cell::RefCell, collections::{BTreeMap}, path::{Path, PathBuf}, rc::Rc};
-use nom::{IResult, Parser, branch::alt, bytes::complete::{tag, take_while1}, combinator::{all_consuming, map}, sequence::{preceded, separated_pair}};
+use aoc_core::{Answer, AocError, ParseResult, Result, Solution, parse_input_line, token};
+use aoc_utils::number;
+use nom::{Parser, branch::alt, bytes::complete::take_while1, combinator::map, error::context, sequence::{preceded, separated_pair}};
 
 
 #[derive(Debug, PartialEq)]
@@ -33,7 +35,7 @@ enum Line<'l> {
 
 
 #[derive(Default)]
-struct TreeNode {
+pub struct TreeNode {
     parent: Option<Rc<RefCell<TreeNode>>>,
     children: BTreeMap<PathBuf, Rc<RefCell<TreeNode>>>,
     size: usize
@@ -64,30 +66,30 @@ impl TreeNode {
 }
 
 
-fn parse_path(input: &str) -> IResult<&str, &Path> {
-    let path_parser = take_while1(|character: char| matches!(character, 'a'..='z' | '.' | '/'));
+fn parse_path(input: &str) -> ParseResult<'_, &Path> {
+    let path_parser = context("a path", take_while1(|character: char| matches!(character, 'a'..='z' | '.' | '/')));
     let mut parser = map(path_parser, |path: &str| Path::new(path));
 
     parser.parse(input)
 }
 
 
-fn parse_list_command(input: &str) -> IResult<&str, Ls> {
-    let mut parser = map(tag("ls"), |_| Ls);
+fn parse_list_command(input: &str) -> ParseResult<'_, Ls> {
+    let mut parser = map(token("ls"), |_| Ls);
 
     parser.parse(input)
 }
 
 
-fn parse_change_directory_command<'l>(input: &'l str) -> IResult<&'l str, Cd<'l>> {
-    let mut parser = map(preceded(tag("cd "), parse_path), Cd);
+fn parse_change_directory_command<'l>(input: &'l str) -> ParseResult<'l, Cd<'l>> {
+    let mut parser = map(preceded(token("cd "), parse_path), Cd);
 
     parser.parse(input)
 }
 
 
-fn parse_command<'l>(input: &'l str) -> IResult<&'l str, Command<'l>> {
-    let mut prompt_parser = tag("$ ");
+fn parse_command<'l>(input: &'l str) -> ParseResult<'l, Command<'l>> {
+    let mut prompt_parser = token("$ ");
     let (input, _) = prompt_parser.parse(input)?;
 
     let mut command_parser = alt((
@@ -99,9 +101,10 @@ fn parse_command<'l>(input: &'l str) -> IResult<&'l str, Command<'l>> {
 }
 
 
-fn parse_entry(input: &str) -> IResult<&str, Entry> {
-    let parse_directory = map(preceded(tag("dir "), parse_path), |path: &Path| Entry::Directory(path.to_owned()));
-    let parse_file = map(separated_pair(nom::character::complete::u64, tag(" "), parse_path), |(size, path)| Entry::File(size, path.to_owned()));
+fn parse_entry(input: &str) -> ParseResult<'_, Entry> {
+    let parse_directory = map(preceded(token("dir "), parse_path), |path: &Path| Entry::Directory(path.to_owned()));
+    let parse_size = context("a file size", number::<u64, _>);
+    let parse_file = map(separated_pair(parse_size, token(" "), parse_path), |(size, path)| Entry::File(size, path.to_owned()));
 
     let mut parser = alt((parse_file, parse_directory));
 
@@ -109,7 +112,7 @@ fn parse_entry(input: &str) -> IResult<&str, Entry> {
 }
 
 
-fn parse_line<'l>(input: &'l str) -> IResult<&'l str, Line<'l>> {
+fn parse_line<'l>(input: &'l str) -> ParseResult<'l, Line<'l>> {
     let mut parser = alt((
         map(parse_command, Line::Command), 
         map(parse_entry, Line::Entry)
@@ -127,40 +130,41 @@ fn solve_part_1(root: Rc<RefCell<TreeNode>>) -> u64 {
 } 
 
 
-fn solve_part_2(root: Rc<RefCell<TreeNode>>) -> u64 {
+fn solve_part_2(root: Rc<RefCell<TreeNode>>) -> Result<u64> {
     let total_space = 70000000_u64;
     let used_space = root.borrow().total_size();
-    let free_space = total_space.checked_sub(used_space).unwrap();
+    let free_space = total_space.checked_sub(used_space)
+        .ok_or_else(|| AocError::solve(format!("filesystem uses {used_space}, more than the disk size {total_space}")))?;
     let needed_free_space = 30000000_u64;
-    let minimum_space_to_free = needed_free_space.checked_sub(free_space).unwrap();
+    let minimum_space_to_free = needed_free_space.checked_sub(free_space)
+        .ok_or_else(|| AocError::solve(format!("disk already has {free_space} free, no directory needs to be deleted")))?;
 
     all_dirs(root).map(|d| d.borrow().total_size())
         .filter(|&s| s >= minimum_space_to_free)
         .min()
-        .unwrap()
+        .ok_or_else(|| AocError::solve(format!("no directory is large enough to free {minimum_space_to_free}")))
 }
 
 
-fn main() {
-    let input = include_str!("input.txt");
-    let lines = input.lines()
-        .map(|line| all_consuming(parse_line).parse(line).unwrap().1);
-
+fn build_tree(input: &str) -> Result<Rc<RefCell<TreeNode>>> {
     let root = Rc::new(RefCell::new(TreeNode::default()));
     let mut node = root.clone();
 
-    for line in lines {
+    for (index, text) in input.lines().enumerate() {
+        let line = parse_input_line(parse_line, text, index + 1)?;
+
         match line {
             Line::Command(cmd) => match cmd {
                 Command::List(_) => {
                     // we are parsing file line by line, we do not have to do anything here
                 }
-                Command::ChangeDirectory(Cd(path)) => match path.to_str().unwrap() {
-                    "/" => {
+                Command::ChangeDirectory(Cd(path)) => match path.to_str() {
+                    Some("/") => {
                         // ignore, we're already there
                     }
-                    ".." => {
-                        let parent = node.borrow().parent.clone().unwrap();
+                    Some("..") => {
+                        let parent = node.borrow().parent.clone()
+                            .ok_or_else(|| AocError::parse(index + 1, 6, "cannot leave the root directory"))?;
                         node = parent;
                     }
                     _ => {
@@ -183,11 +187,36 @@ fn main() {
         }
     }
 
-    let part_1 = solve_part_1(root.clone());
-    let part_2 = solve_part_2(root.clone());
+    Ok(root)
+}
+
+
+#[cfg(feature = "embedded")]
+pub const INPUT: &str = include_str!("input.txt");
+
+
+pub struct Day7;
+
+
+impl Solution for Day7 {
+    type Input = Rc<RefCell<TreeNode>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        build_tree(input)
+    }
+
+    fn part1(&self, root: &Self::Input) -> Result<Answer> {
+        Ok(solve_part_1(root.clone()).into())
+    }
+
+    fn part2(&self, root: &Self::Input) -> Result<Answer> {
+        solve_part_2(root.clone()).map(Answer::from)
+    }
 
-    println!("Part 1 result: {part_1}");
-    println!("Part 2 result: {part_2}");
+    #[cfg(feature = "embedded")]
+    fn embedded_input(&self) -> Option<&'static str> {
+        Some(INPUT)
+    }
 }
 
 
@@ -212,7 +241,8 @@ fn all_dirs(n: Rc<RefCell<TreeNode>>) -> Box<dyn Iterator<Item = Rc<RefCell<Tree
 #[cfg(test)]
 mod test {
     use std::path::{Path, PathBuf};
-    use crate::{Line, Cd, Command, Entry, Ls, parse_command, parse_entry, parse_line, parse_path};
+    use aoc_core::AocError;
+    use crate::{Line, Cd, Command, Entry, Ls, build_tree, parse_command, parse_entry, parse_line, parse_path};
 
 
     #[test]
@@ -304,4 +334,20 @@ mod test {
             Ok(("", Line::Entry(Entry::Directory(PathBuf::from("btcjthr")))))
         );  
     }
-}
\ No newline at end of file
+
+
+    #[test]
+    fn test_build_tree_errors() {
+        let error = build_tree("$ cd /\n$ ls\n12 a.txt\n$ rm a.txt").unwrap_err();
+        assert_eq!(error.to_string(), "line 4, column 3: expected `ls` or `cd `");
+
+        let error = build_tree("$ cd /\n$ ls\n12 A.txt").unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 4: expected a path");
+
+        let error = build_tree("$ cd /\n$ ls\nfile a.txt").unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 1: expected `$ `, a file size or `dir `");
+
+        let error = build_tree("$ cd /\n$ cd ..").unwrap_err();
+        assert!(matches!(error, AocError::Parse { line: 2, column: 6, .. }), "{error}");
+    }
+}
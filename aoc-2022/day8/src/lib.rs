@@ -0,0 +1,132 @@
+use aoc_core::{Answer, AocError, Result, Solution};
+use aoc_utils::{Coordinate, Grid};
+
+
+fn solve_part_1(grid: &Grid<usize>, coordinates: impl Iterator<Item = Coordinate>) -> usize {
+    coordinates.filter(|&coordinate| {
+        let Some(coordinate_height) = grid.get_cell(coordinate) else {
+            return false;
+        };
+
+        let directions: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+        
+        directions.iter().any(|&direction| {
+            grid.ray(coordinate, direction).all(|height: &usize| height < coordinate_height)
+        })
+    })
+    .count()
+}
+
+
+fn solve_part_2(grid: &Grid<usize>, coordinates: impl Iterator<Item = Coordinate>) -> Option<(Coordinate, usize)> {
+    coordinates.filter_map(|coordinate| {
+        let current_height = grid.get_cell(coordinate)?;
+        let directions: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+        
+        let score = directions.into_iter()
+            .map(|direction| {
+                let direction_heights = grid.ray(coordinate, direction);
+
+                let mut score = 0;
+
+                for direction_height in direction_heights {
+                    score += 1;
+
+                    if direction_height >= current_height {
+                        break;
+                    }
+                }
+
+                score
+            })
+            .product();
+
+        Some((coordinate, score))
+    })
+    .max_by_key(|(_, score)| *score)
+}
+
+
+#[cfg(feature = "embedded")]
+pub const INPUT: &str = include_str!("input.txt");
+
+
+pub struct Day8;
+
+
+impl Solution for Day8 {
+    type Input = Grid<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        create_grid(input)
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Result<Answer> {
+        Ok(solve_part_1(grid, grid.coordinates()).into())
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Result<Answer> {
+        solve_part_2(grid, grid.coordinates())
+            .map(|(coordinate, score)| ((coordinate.row, coordinate.column), score).into())
+            .ok_or_else(|| AocError::solve("grid has no trees"))
+    }
+
+    #[cfg(feature = "embedded")]
+    fn embedded_input(&self) -> Option<&'static str> {
+        Some(INPUT)
+    }
+}
+
+
+fn create_grid(input: &str) -> Result<Grid<usize>> {
+    let rows = input.lines().count();
+    let columns = input.lines().next()
+        .map(|line| line.len())
+        .ok_or_else(|| AocError::parse(1, 1, "expected at least one row of trees"))?;
+
+    let mut grid = Grid::new(rows, columns);
+
+    for (row, line) in input.lines().enumerate() {
+        if line.len() != columns {
+            return Err(AocError::parse(row + 1, line.len().min(columns) + 1, format!("expected every row to have {columns} trees, found {}", line.len())));
+        }
+
+        for (column, character) in line.chars().enumerate() {
+            let height = character.to_digit(10)
+                .ok_or_else(|| AocError::parse(row + 1, column + 1, format!("expected a tree height digit, found `{character}`")))?;
+
+            if let Some(cell) = grid.get_cell_mut((row, column).into()) {
+                *cell = height as usize;
+            }
+        }
+    }
+
+    Ok(grid)
+}
+
+
+#[cfg(test)]
+mod test {
+    use aoc_core::AocError;
+    use crate::{create_grid, solve_part_1, solve_part_2};
+
+    #[test]
+    fn test_example() {
+        let grid = create_grid("30373\n25512\n65332\n33549\n35390").unwrap();
+
+        assert_eq!(solve_part_1(&grid, grid.coordinates()), 21);
+        assert_eq!(solve_part_2(&grid, grid.coordinates()).map(|(_, score)| score), Some(8));
+    }
+
+
+    #[test]
+    fn test_create_grid_errors() {
+        let error = create_grid("303\n2x5").err().unwrap();
+        assert!(matches!(error, AocError::Parse { line: 2, column: 2, .. }), "{error}");
+
+        let error = create_grid("303\n25").err().unwrap();
+        assert!(matches!(error, AocError::Parse { line: 2, column: 3, .. }), "{error}");
+
+        assert!(create_grid("").is_err());
+    }
+}
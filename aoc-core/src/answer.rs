@@ -0,0 +1,90 @@
+use std::fmt;
+
+
+/// The answer to one part of a puzzle.
+///
+/// Solvers return whatever shape their puzzle asks for and the runner only ever
+/// needs to print and compare it, so every answer is reduced to one of these forms.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i128),
+    String(String),
+    Composite(Vec<Answer>)
+}
+
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(integer) => write!(f, "{integer}"),
+            Answer::String(string) => write!(f, "{string}"),
+            Answer::Composite(answers) => {
+                write!(f, "(")?;
+
+                for (index, answer) in answers.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    write!(f, "{answer}")?;
+                }
+
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+
+macro_rules! impl_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    Answer::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::String(value)
+    }
+}
+
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::String(value.to_owned())
+    }
+}
+
+
+impl<A, B> From<(A, B)> for Answer
+where
+    A: Into<Answer>,
+    B: Into<Answer>
+{
+    fn from((first, second): (A, B)) -> Self {
+        Answer::Composite(vec![first.into(), second.into()])
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use crate::Answer;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(42_u64).to_string(), "42");
+        assert_eq!(Answer::from(-7_i32).to_string(), "-7");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+        assert_eq!(Answer::from(((3_usize, 2_usize), 8_usize)).to_string(), "((3, 2), 8)");
+    }
+}
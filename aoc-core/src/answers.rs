@@ -0,0 +1,139 @@
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Answer, Part};
+
+
+/// Known answers for both parts of a single day.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>
+}
+
+
+impl DayAnswers {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref()
+        }
+    }
+
+
+    fn set(&mut self, part: Part, answer: String) {
+        match part {
+            Part::One => self.part1 = Some(answer),
+            Part::Two => self.part2 = Some(answer)
+        }
+    }
+}
+
+
+/// Recorded answers, keyed by profile, year and day.
+///
+/// Answers are stored as their rendered text so the file stays readable and a
+/// solver can change its [`Answer`] shape without invalidating what was recorded.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AnswerDatabase {
+    profiles: BTreeMap<String, BTreeMap<u16, BTreeMap<u8, DayAnswers>>>
+}
+
+
+impl AnswerDatabase {
+    /// Loads the database, treating a missing file as an empty one.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {error}", path.display()))),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error)
+        }
+    }
+
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut contents = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        contents.push('\n');
+
+        fs::write(path, contents)
+    }
+
+
+    pub fn profiles(&self) -> impl Iterator<Item = &str> {
+        self.profiles.keys().map(String::as_str)
+    }
+
+
+    pub fn day(&self, profile: &str, year: u16, day: u8) -> Option<&DayAnswers> {
+        self.profiles.get(profile)?.get(&year)?.get(&day)
+    }
+
+
+    pub fn get(&self, profile: &str, year: u16, day: u8, part: Part) -> Option<&str> {
+        self.day(profile, year, day)?.get(part)
+    }
+
+
+    /// Adds an empty entry for the day so it shows up in the file before it is solved.
+    pub fn add_day(&mut self, profile: &str, year: u16, day: u8) {
+        self.day_mut(profile, year, day);
+    }
+
+
+    pub fn record(&mut self, profile: &str, year: u16, day: u8, part: Part, answer: &Answer) {
+        self.day_mut(profile, year, day).set(part, answer.to_string());
+    }
+
+
+    fn day_mut(&mut self, profile: &str, year: u16, day: u8) -> &mut DayAnswers {
+        self.profiles.entry(profile.to_owned())
+            .or_default()
+            .entry(year)
+            .or_default()
+            .entry(day)
+            .or_default()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use crate::{Answer, AnswerDatabase, Part};
+
+    #[test]
+    fn test_record() {
+        let mut answers = AnswerDatabase::default();
+        answers.record("alice", 2022, 5, Part::One, &Answer::from("CMZ"));
+        answers.record("alice", 2022, 8, Part::Two, &Answer::from(((3_usize, 2_usize), 8_usize)));
+        answers.add_day("bob", 2022, 4);
+
+        assert_eq!(answers.get("alice", 2022, 5, Part::One), Some("CMZ"));
+        assert_eq!(answers.get("alice", 2022, 5, Part::Two), None);
+        assert_eq!(answers.get("alice", 2022, 8, Part::Two), Some("((3, 2), 8)"));
+        assert_eq!(answers.get("bob", 2022, 4, Part::One), None);
+        assert_eq!(answers.profiles().collect::<Vec<_>>(), vec!["alice", "bob"]);
+    }
+
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = AnswerDatabase::default();
+        answers.record("alice", 2022, 7, Part::One, &Answer::from(95437_u64));
+        answers.add_day("alice", 2022, 9);
+
+        let path = std::env::temp_dir().join(format!("aoc-core-answers-{}.json", std::process::id()));
+        answers.save(&path).unwrap();
+
+        assert_eq!(AnswerDatabase::load(&path).unwrap(), answers);
+        assert!(std::fs::read_to_string(&path).unwrap().contains("\"part1\": \"95437\""));
+
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(AnswerDatabase::load(&path).unwrap(), AnswerDatabase::default());
+    }
+}
@@ -0,0 +1,236 @@
+use std::fmt;
+
+use nom::{Finish, IResult, Parser, combinator::all_consuming, error::{ContextError, ErrorKind, FromExternalError, ParseError}};
+
+use crate::AocError;
+
+
+/// Result of a nom parser that reports what it expected when it fails.
+pub type ParseResult<'a, T> = IResult<&'a str, T, ParseFailure<'a>>;
+
+
+/// Something a parser was looking for when it failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expected {
+    /// A literal piece of text, rendered in backticks.
+    Token(&'static str),
+
+    /// A description of a syntactic element, e.g. "a crate letter".
+    Label(&'static str),
+
+    /// A bare nom error kind, used when nothing more specific is known.
+    Kind(ErrorKind)
+}
+
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Token(token) => write!(f, "`{token}`"),
+            Expected::Label(label) => write!(f, "{label}"),
+            Expected::Kind(ErrorKind::Eof) => write!(f, "end of line"),
+            Expected::Kind(ErrorKind::Digit) => write!(f, "a number"),
+            Expected::Kind(kind) => write!(f, "{}", kind.description().to_lowercase())
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Reason {
+    Expected(Vec<Expected>),
+    Invalid(String)
+}
+
+
+/// nom error type that remembers the furthest position any alternative reached and
+/// what was expected there, instead of the last alternative tried.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseFailure<'a> {
+    input: &'a str,
+    reason: Reason
+}
+
+
+impl<'a> ParseFailure<'a> {
+    pub fn expected(input: &'a str, expected: Expected) -> Self {
+        Self {
+            input,
+            reason: Reason::Expected(vec![expected])
+        }
+    }
+
+
+    /// The unparsed input at the point of failure.
+    pub fn input(&self) -> &'a str {
+        self.input
+    }
+
+
+    pub fn message(&self) -> String {
+        match &self.reason {
+            Reason::Invalid(message) => message.clone(),
+            Reason::Expected(expected) => {
+                let expected: Vec<String> = expected.iter().map(ToString::to_string).collect();
+
+                match expected.split_last() {
+                    Some((last, [])) => format!("expected {last}"),
+                    Some((last, rest)) => format!("expected {} or {last}", rest.join(", ")),
+                    None => "unexpected input".to_string()
+                }
+            }
+        }
+    }
+
+
+    /// Converts the failure into an [`AocError`] for `line`, the full text the parser
+    /// was given.
+    pub fn into_error(self, line: &str, line_number: usize) -> AocError {
+        let offset = line.len().saturating_sub(self.input.len());
+        let column = line.get(..offset).map_or(offset, |prefix| prefix.chars().count()) + 1;
+
+        AocError::parse(line_number, column, self.message())
+    }
+}
+
+
+impl<'a> ParseError<&'a str> for ParseFailure<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        Self::expected(input, Expected::Kind(kind))
+    }
+
+
+    fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+
+    fn or(self, other: Self) -> Self {
+        match self.input.len().cmp(&other.input.len()) {
+            std::cmp::Ordering::Less => self,
+            std::cmp::Ordering::Greater => other,
+            std::cmp::Ordering::Equal => match (self.reason, other.reason) {
+                (Reason::Expected(mut expected), Reason::Expected(others)) => {
+                    for other in others {
+                        if !expected.contains(&other) {
+                            expected.push(other);
+                        }
+                    }
+
+                    Self {
+                        input: self.input,
+                        reason: Reason::Expected(expected)
+                    }
+                }
+                (reason @ Reason::Invalid(_), _) | (_, reason) => Self {
+                    input: self.input,
+                    reason
+                }
+            }
+        }
+    }
+}
+
+
+impl<'a> ContextError<&'a str> for ParseFailure<'a> {
+    /// Replaces the inner expectation with the label, but only when the labelled parser
+    /// failed right where it started; a failure further in is more precise.
+    fn add_context(input: &'a str, context: &'static str, other: Self) -> Self {
+        if other.input.len() == input.len() && matches!(other.reason, Reason::Expected(_)) {
+            Self::expected(input, Expected::Label(context))
+        } else {
+            other
+        }
+    }
+}
+
+
+impl<'a, E: fmt::Display> FromExternalError<&'a str, E> for ParseFailure<'a> {
+    fn from_external_error(input: &'a str, _kind: ErrorKind, error: E) -> Self {
+        Self {
+            input,
+            reason: Reason::Invalid(error.to_string())
+        }
+    }
+}
+
+
+/// Matches a literal token, reporting it by name when it is missing.
+pub fn token<'a>(token: &'static str) -> impl Parser<&'a str, Output = &'a str, Error = ParseFailure<'a>> {
+    move |input: &'a str| match input.strip_prefix(token) {
+        Some(rest) => Ok((rest, &input[..token.len()])),
+        None => Err(nom::Err::Error(ParseFailure::expected(input, Expected::Token(token))))
+    }
+}
+
+
+/// Runs the parser over a whole line, turning any failure into an [`AocError`] that
+/// points at the offending column.
+pub fn parse_input_line<'a, O, P>(parser: P, line: &'a str, line_number: usize) -> Result<O, AocError>
+where
+    P: Parser<&'a str, Output = O, Error = ParseFailure<'a>>
+{
+    all_consuming(parser)
+        .parse(line)
+        .finish()
+        .map(|(_, output)| output)
+        .map_err(|failure| failure.into_error(line, line_number))
+}
+
+
+/// Renders the source line with a caret under `column`, like compiler diagnostics.
+pub fn render_caret(line: usize, column: usize, source_line: &str) -> String {
+    let gutter = " ".repeat(line.to_string().len());
+    let padding = " ".repeat(column.saturating_sub(1));
+
+    format!("{gutter} |\n{line} | {source_line}\n{gutter} | {padding}^")
+}
+
+
+#[cfg(test)]
+mod test {
+    use nom::{branch::alt, character::complete::digit1, error::context, sequence::preceded};
+    use crate::{AocError, diagnostic::{parse_input_line, render_caret, token}};
+
+    fn parse_move(line: &str) -> Result<(&str, &str), AocError> {
+        let parser = (
+            preceded(token("move "), context("a number", digit1)),
+            preceded(token(" from "), context("a number", digit1))
+        );
+
+        parse_input_line(parser, line, 7)
+    }
+
+
+    #[test]
+    fn test_expected_token() {
+        assert_eq!(parse_move("move 3 from 1").unwrap(), ("3", "1"));
+
+        let error = parse_move("move 3 frm 1").unwrap_err();
+        assert_eq!(error.to_string(), "line 7, column 7: expected ` from `");
+
+        let error = parse_move("move x from 1").unwrap_err();
+        assert_eq!(error.to_string(), "line 7, column 6: expected a number");
+
+        let error = parse_move("move 3 from 1 to 2").unwrap_err();
+        assert_eq!(error.to_string(), "line 7, column 14: expected end of line");
+    }
+
+
+    #[test]
+    fn test_alternatives() {
+        let error = parse_input_line(alt((token("$ ls"), token("$ cd"))), "$ rm", 1).unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 1: expected `$ ls` or `$ cd`");
+
+        let furthest = alt(((token("dir"), token(" a")), (token("d"), token("x"))));
+        let error = parse_input_line(furthest, "dir b", 2).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 4: expected ` a`");
+    }
+
+
+    #[test]
+    fn test_render_caret() {
+        assert_eq!(render_caret(7, 7, "move 3 frm 1"), "  |\n7 | move 3 frm 1\n  |       ^");
+        assert_eq!(render_caret(12, 1, "[m]"), "   |\n12 | [m]\n   | ^");
+    }
+}
@@ -0,0 +1,113 @@
+use std::{fmt, io};
+
+use crate::diagnostic::render_caret;
+
+
+pub type Result<T, E = AocError> = std::result::Result<T, E>;
+
+
+/// Everything that can go wrong while loading, parsing or solving a puzzle.
+#[derive(Debug)]
+pub enum AocError {
+    Io(io::Error),
+
+    /// The input does not match the puzzle format. `line` and `column` are 1-based.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+        source_line: Option<String>
+    },
+
+    /// The input parsed fine but has no answer, e.g. a datastream without a marker.
+    Solve(String)
+}
+
+
+impl AocError {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        AocError::Parse {
+            line,
+            column,
+            message: message.into(),
+            source_line: None
+        }
+    }
+
+
+    /// Attaches the offending line of the input so the error renders it with a caret.
+    /// Errors that already carry a line, or are not parse errors, are left untouched.
+    pub fn with_source(self, input: &str) -> Self {
+        match self {
+            AocError::Parse { line, column, message, source_line: None } => AocError::Parse {
+                source_line: line.checked_sub(1).and_then(|index| input.lines().nth(index)).map(str::to_owned),
+                line,
+                column,
+                message
+            },
+            error => error
+        }
+    }
+
+
+    pub fn solve(message: impl Into<String>) -> Self {
+        AocError::Solve(message.into())
+    }
+}
+
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io(error) => write!(f, "{error}"),
+            AocError::Parse { line, column, message, source_line } => {
+                write!(f, "line {line}, column {column}: {message}")?;
+
+                match source_line {
+                    Some(source_line) => write!(f, "\n{}", render_caret(*line, *column, source_line)),
+                    None => Ok(())
+                }
+            }
+            AocError::Solve(message) => write!(f, "{message}")
+        }
+    }
+}
+
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io(error) => Some(error),
+            _ => None
+        }
+    }
+}
+
+
+impl From<io::Error> for AocError {
+    fn from(error: io::Error) -> Self {
+        AocError::Io(error)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use crate::AocError;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(AocError::parse(3, 5, "expected a digit").to_string(), "line 3, column 5: expected a digit");
+        assert_eq!(AocError::solve("no marker found").to_string(), "no marker found");
+
+        let error = AocError::parse(2, 3, "expected `-`").with_source("2-4,6-8\n2-3,4-5\n");
+        assert_eq!(error.to_string(), "line 2, column 3: expected `-`\n  |\n2 | 2-3,4-5\n  |   ^");
+    }
+
+
+    #[test]
+    fn test_with_source_out_of_range() {
+        let error = AocError::parse(5, 1, "expected an instruction").with_source("a\nb");
+        assert_eq!(error.to_string(), "line 5, column 1: expected an instruction");
+    }
+}
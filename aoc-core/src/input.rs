@@ -0,0 +1,68 @@
+use std::{borrow::Cow, fs, io::{self, Read}, path::PathBuf};
+
+
+/// Where a puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input<'a> {
+    Path(PathBuf),
+    Stdin,
+    Text(&'a str)
+}
+
+
+impl Input<'_> {
+    /// Reads the whole input, borrowing it when it is already in memory.
+    pub fn read(&self) -> io::Result<Cow<'_, str>> {
+        match self {
+            Input::Path(path) => fs::read_to_string(path)
+                .map(Cow::Owned)
+                .map_err(|error| io::Error::new(error.kind(), format!("{}: {error}", path.display()))),
+            Input::Stdin => {
+                let mut buffer = String::new();
+                io::stdin().read_to_string(&mut buffer)?;
+
+                Ok(Cow::Owned(buffer))
+            }
+            Input::Text(text) => Ok(Cow::Borrowed(text))
+        }
+    }
+}
+
+
+impl From<PathBuf> for Input<'_> {
+    /// Treats `-` as standard input, as most command line tools do.
+    fn from(path: PathBuf) -> Self {
+        if path.as_os_str() == "-" {
+            Input::Stdin
+        } else {
+            Input::Path(path)
+        }
+    }
+}
+
+
+impl<'a> From<&'a str> for Input<'a> {
+    fn from(text: &'a str) -> Self {
+        Input::Text(text)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::PathBuf};
+    use crate::Input;
+
+    #[test]
+    fn test_read() {
+        assert_eq!(Input::from("30373\n25512").read().unwrap(), "30373\n25512");
+
+        let path = std::env::temp_dir().join(format!("aoc-core-input-{}.txt", std::process::id()));
+        fs::write(&path, "mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap();
+        assert_eq!(Input::from(path.clone()).read().unwrap(), "mjqjpqmgbljsphdztnvjfqwrcgsmlb");
+        fs::remove_file(&path).unwrap();
+
+        assert!(Input::from(path).read().is_err());
+        assert_eq!(Input::from(PathBuf::from("-")), Input::Stdin);
+    }
+}
@@ -0,0 +1,17 @@
+mod answer;
+mod answers;
+mod diagnostic;
+mod error;
+mod input;
+mod registry;
+mod solution;
+mod store;
+
+pub use answer::Answer;
+pub use answers::{AnswerDatabase, DayAnswers};
+pub use diagnostic::{Expected, ParseFailure, ParseResult, parse_input_line, render_caret, token};
+pub use error::{AocError, Result};
+pub use input::Input;
+pub use registry::{DynSolution, ParsedInput, Registry};
+pub use solution::{Part, Solution};
+pub use store::{DEFAULT_PROFILE, DirectoryFetcher, Fetcher, InputStore};
@@ -0,0 +1,141 @@
+use std::collections::BTreeMap;
+
+use crate::{Answer, Input, Part, Result, Solution};
+
+
+/// Input that has already been parsed by a type-erased solution.
+pub trait ParsedInput {
+    fn part1(&self) -> Result<Answer>;
+
+    fn part2(&self) -> Result<Answer>;
+
+    fn part(&self, part: Part) -> Result<Answer> {
+        match part {
+            Part::One => self.part1(),
+            Part::Two => self.part2()
+        }
+    }
+}
+
+
+/// Object safe view of a [`Solution`], so solutions with different input types can
+/// live side by side in a [`Registry`].
+pub trait DynSolution {
+    fn parse<'s>(&'s self, input: &str) -> Result<Box<dyn ParsedInput + 's>>;
+
+    fn embedded_input(&self) -> Option<&'static str>;
+
+    /// Reads the input from wherever it lives and parses it.
+    fn load<'s>(&'s self, input: &Input) -> Result<Box<dyn ParsedInput + 's>> {
+        self.parse(&input.read()?)
+    }
+}
+
+
+struct Parsed<'s, S: Solution> {
+    solution: &'s S,
+    input: S::Input
+}
+
+
+impl<S: Solution> ParsedInput for Parsed<'_, S> {
+    fn part1(&self) -> Result<Answer> {
+        self.solution.part1(&self.input)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        self.solution.part2(&self.input)
+    }
+}
+
+
+impl<S: Solution> DynSolution for S {
+    fn parse<'s>(&'s self, input: &str) -> Result<Box<dyn ParsedInput + 's>> {
+        Ok(Box::new(Parsed {
+            solution: self,
+            input: Solution::parse(self, input).map_err(|error| error.with_source(input))?
+        }))
+    }
+
+    fn embedded_input(&self) -> Option<&'static str> {
+        Solution::embedded_input(self)
+    }
+}
+
+
+/// Every known solution, keyed by `(year, day)`.
+#[derive(Default)]
+pub struct Registry {
+    solutions: BTreeMap<(u16, u8), Box<dyn DynSolution>>
+}
+
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+
+    pub fn register<S>(&mut self, year: u16, day: u8, solution: S) -> &mut Self
+    where
+        S: Solution + 'static
+    {
+        self.solutions.insert((year, day), Box::new(solution));
+        self
+    }
+
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&dyn DynSolution> {
+        self.solutions.get(&(year, day)).map(|solution| solution.as_ref())
+    }
+
+
+    pub fn iter(&self) -> impl Iterator<Item = ((u16, u8), &dyn DynSolution)> {
+        self.solutions.iter().map(|(&key, solution)| (key, solution.as_ref()))
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use crate::{Answer, AocError, Registry, Result, Solution};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+
+        fn parse(&self, input: &str) -> Result<Self::Input> {
+            input.lines()
+                .enumerate()
+                .map(|(index, line)| line.parse().map_err(|error| AocError::parse(index + 1, 1, format!("{error}"))))
+                .collect()
+        }
+
+        fn part1(&self, input: &Self::Input) -> Result<Answer> {
+            Ok(input.iter().sum::<u32>().into())
+        }
+
+        fn part2(&self, input: &Self::Input) -> Result<Answer> {
+            input.iter().max().map(|&max| max.into()).ok_or_else(|| AocError::solve("empty input"))
+        }
+    }
+
+
+    #[test]
+    fn test_registry() {
+        let mut registry = Registry::new();
+        registry.register(2022, 1, Sum);
+
+        assert!(registry.get(2022, 2).is_none());
+
+        let parsed = registry.get(2022, 1).unwrap().load(&"1\n5\n3".into()).unwrap();
+        assert_eq!(parsed.part1().unwrap(), Answer::Integer(9));
+        assert_eq!(parsed.part2().unwrap(), Answer::Integer(5));
+
+        let error = registry.get(2022, 1).unwrap().load(&"1\nx".into()).err().unwrap();
+        assert!(matches!(error, AocError::Parse { line: 2, column: 1, .. }));
+
+        assert_eq!(registry.iter().map(|(key, _)| key).collect::<Vec<_>>(), vec![(2022, 1)]);
+    }
+}
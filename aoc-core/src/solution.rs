@@ -0,0 +1,60 @@
+use std::fmt;
+
+use crate::{Answer, Result};
+
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two
+}
+
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2")
+        }
+    }
+}
+
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("Puzzles only have parts 1 and 2, got {value}"))
+        }
+    }
+}
+
+
+/// A puzzle solver for a single day.
+///
+/// The input is parsed once by [`Solution::parse`] and the result is shared by both
+/// parts, so solvers never have to read the puzzle input twice.
+pub trait Solution {
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer>;
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer>;
+
+    /// The puzzle input compiled into the binary, available when the day crate is
+    /// built with its `embedded` feature.
+    fn embedded_input(&self) -> Option<&'static str> {
+        None
+    }
+}
@@ -0,0 +1,203 @@
+use std::{env, fs, io, path::{Path, PathBuf}};
+
+use crate::Input;
+
+
+/// Profile used when none is given explicitly.
+pub const DEFAULT_PROFILE: &str = "default";
+
+
+/// Source of puzzle inputs that are not in the store yet.
+pub trait Fetcher {
+    fn fetch(&self, profile: &str, year: u16, day: u8) -> io::Result<String>;
+}
+
+
+/// Fetches inputs from a directory laid out exactly like an [`InputStore`], e.g. a
+/// shared drive or a fixture directory in tests.
+pub struct DirectoryFetcher {
+    root: PathBuf
+}
+
+
+impl DirectoryFetcher {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+
+impl Fetcher for DirectoryFetcher {
+    fn fetch(&self, profile: &str, year: u16, day: u8) -> io::Result<String> {
+        let path = input_path(&self.root, profile, year, day);
+
+        fs::read_to_string(&path)
+            .map_err(|error| io::Error::new(error.kind(), format!("{}: {error}", path.display())))
+    }
+}
+
+
+/// Local cache of puzzle inputs, stored as `<root>/<profile>/<year>/<day>.txt`.
+///
+/// Inputs differ per account, so every profile gets its own directory.
+pub struct InputStore {
+    root: PathBuf,
+    profile: String
+}
+
+
+impl InputStore {
+    pub fn new(root: impl Into<PathBuf>, profile: impl Into<String>) -> Self {
+        Self {
+            root: root.into(),
+            profile: profile.into()
+        }
+    }
+
+
+    /// `$AOC_CACHE_DIR`, falling back to `$XDG_CACHE_HOME/aoc` and `~/.cache/aoc`.
+    pub fn default_root() -> Option<PathBuf> {
+        if let Some(root) = env::var_os("AOC_CACHE_DIR") {
+            return Some(root.into());
+        }
+
+        env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))
+            .map(|cache| cache.join("aoc"))
+    }
+
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+
+    pub fn profile(&self) -> &str {
+        &self.profile
+    }
+
+
+    /// Every profile that has at least one stored input.
+    pub fn profiles(&self) -> io::Result<Vec<String>> {
+        let entries = match fs::read_dir(&self.root) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => return Err(error)
+        };
+
+        let mut profiles = Vec::new();
+
+        for entry in entries {
+            let entry = entry?;
+
+            if entry.file_type()?.is_dir() {
+                profiles.push(entry.file_name().to_string_lossy().into_owned());
+            }
+        }
+
+        profiles.sort();
+
+        Ok(profiles)
+    }
+
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        input_path(&self.root, &self.profile, year, day)
+    }
+
+
+    /// The stored input for the day, if there is one.
+    pub fn get(&self, year: u16, day: u8) -> Option<Input<'static>> {
+        let path = self.path(year, day);
+
+        path.is_file().then_some(Input::Path(path))
+    }
+
+
+    /// Copies an input into the store, replacing whatever was stored before.
+    pub fn import(&self, year: u16, day: u8, input: &Input) -> io::Result<PathBuf> {
+        self.save(year, day, &input.read()?)
+    }
+
+
+    /// Stores the day's input from the fetcher unless it is already present.
+    pub fn fetch(&self, year: u16, day: u8, fetcher: &dyn Fetcher) -> io::Result<PathBuf> {
+        let path = self.path(year, day);
+
+        if path.is_file() {
+            return Ok(path);
+        }
+
+        self.save(year, day, &fetcher.fetch(&self.profile, year, day)?)
+    }
+
+
+    fn save(&self, year: u16, day: u8, contents: &str) -> io::Result<PathBuf> {
+        let path = self.path(year, day);
+
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+
+        fs::write(&path, contents)?;
+
+        Ok(path)
+    }
+}
+
+
+fn input_path(root: &Path, profile: &str, year: u16, day: u8) -> PathBuf {
+    root.join(profile).join(year.to_string()).join(format!("{day}.txt"))
+}
+
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::PathBuf};
+    use crate::{DirectoryFetcher, Input, InputStore};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("aoc-core-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+
+        directory
+    }
+
+
+    #[test]
+    fn test_import() {
+        let root = temp_dir("store-import");
+        let store = InputStore::new(&root, "alice");
+
+        assert_eq!(store.path(2022, 6), root.join("alice").join("2022").join("6.txt"));
+        assert!(store.get(2022, 6).is_none());
+
+        store.import(2022, 6, &Input::Text("bvwbjplbgvbhsrlpgdmjqwftvncz")).unwrap();
+        assert_eq!(store.get(2022, 6).unwrap().read().unwrap(), "bvwbjplbgvbhsrlpgdmjqwftvncz");
+        assert!(InputStore::new(&root, "bob").get(2022, 6).is_none());
+
+        InputStore::new(&root, "bob").import(2022, 6, &Input::Text("nppdvjthqldpwncqszvftbrmjlhg")).unwrap();
+        assert_eq!(store.profiles().unwrap(), vec!["alice", "bob"]);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+
+    #[test]
+    fn test_fetch() {
+        let remote = temp_dir("store-remote");
+        InputStore::new(&remote, "alice").import(2022, 6, &Input::Text("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw")).unwrap();
+
+        let root = temp_dir("store-fetch");
+        let store = InputStore::new(&root, "alice");
+        let fetcher = DirectoryFetcher::new(&remote);
+
+        store.fetch(2022, 6, &fetcher).unwrap();
+        assert_eq!(store.get(2022, 6).unwrap().read().unwrap(), "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw");
+        assert!(store.fetch(2022, 7, &fetcher).is_err());
+
+        fs::remove_dir_all(root).unwrap();
+        fs::remove_dir_all(remote).unwrap();
+    }
+}
@@ -0,0 +1,168 @@
+use std::ops::RangeInclusive;
+
+use crate::{Integer, RangeInclusiveExtension};
+
+
+/// An axis-aligned box in `N` dimensions, one inclusive range per axis.
+///
+/// A box with an empty range on any axis holds no points.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Box<const N: usize, T> {
+    pub axes: [RangeInclusive<T>; N]
+}
+
+
+impl<const N: usize, T: Integer> Box<N, T> {
+    pub fn new(axes: [RangeInclusive<T>; N]) -> Self {
+        Box { axes }
+    }
+
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(RangeInclusive::is_empty)
+    }
+
+
+    pub fn contains(&self, point: &[T; N]) -> bool {
+        self.axes.iter().zip(point).all(|(axis, value)| axis.contains(value))
+    }
+
+
+    /// Whether every point of `other` is also in `self`.
+    pub fn contains_box(&self, other: &Self) -> bool {
+        other.is_empty() || self.axes.iter().zip(&other.axes).all(|(axis, other)| axis.contains_range(other))
+    }
+
+
+    /// Whether the two boxes share at least one point.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+
+    /// The points in both boxes.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut axes = self.axes.clone();
+
+        for (axis, other) in axes.iter_mut().zip(&other.axes) {
+            *axis = axis.intersection(other)?;
+        }
+
+        Some(Box { axes })
+    }
+
+
+    /// How many points the box holds, saturating at `u128::MAX`.
+    pub fn volume(&self) -> u128 {
+        self.axes.iter().fold(1, |volume: u128, axis| volume.saturating_mul(axis.length()))
+    }
+
+
+    /// How many points the two boxes share.
+    pub fn intersection_volume(&self, other: &Self) -> u128 {
+        self.intersection(other).map_or(0, |intersection| intersection.volume())
+    }
+
+
+    /// The points of `self` that are not in `other`, as up to `2 * N` disjoint boxes.
+    ///
+    /// Axis by axis, the slabs of `self` below and above `other` are cut off and what
+    /// is left is narrowed down to `other` on that axis.
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+
+        let Some(overlap) = self.intersection(other) else {
+            return vec![self.clone()];
+        };
+
+        let mut pieces = Vec::new();
+        let mut remaining = self.clone();
+
+        for (axis, overlap_axis) in overlap.axes.iter().enumerate() {
+            let (below, above) = remaining.axes[axis].difference(overlap_axis);
+
+            for part in below.into_iter().chain(above) {
+                let mut piece = remaining.clone();
+                piece.axes[axis] = part;
+                pieces.push(piece);
+            }
+
+            remaining.axes[axis] = overlap_axis.clone();
+        }
+
+        pieces
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use crate::Box;
+
+    #[test]
+    fn test_queries() {
+        let square = Box::new([0..=9, 0..=9]);
+        let inner = Box::new([2..=3, 4..=9]);
+        let apart = Box::new([10..=12, 0..=9]);
+
+        assert!(square.contains(&[9, 0]));
+        assert!(!square.contains(&[10, 0]));
+        assert!(square.contains_box(&inner));
+        assert!(!inner.contains_box(&square));
+        assert!(!square.overlaps(&apart));
+        assert_eq!(square.intersection(&Box::new([5..=20, -3..=1])), Some(Box::new([5..=9, 0..=1])));
+        assert_eq!(square.volume(), 100);
+        assert_eq!(square.intersection_volume(&inner), 12);
+        assert_eq!(square.intersection_volume(&apart), 0);
+    }
+
+
+    #[test]
+    fn test_subtract() {
+        let cube = Box::new([0..=9, 0..=9, 0..=9]);
+        let center = Box::new([3..=5, 3..=5, 3..=5]);
+
+        let pieces = cube.subtract(&center);
+        assert_eq!(pieces.len(), 6);
+        assert_eq!(pieces.iter().map(Box::volume).sum::<u128>(), 1000 - 27);
+        assert!(pieces.iter().all(|piece| !piece.overlaps(&center)));
+
+        for (index, piece) in pieces.iter().enumerate() {
+            assert!(pieces[index + 1..].iter().all(|other| !piece.overlaps(other)));
+        }
+
+        let corner = Box::new([-5..=2, -5..=2, -5..=2]);
+        assert_eq!(cube.subtract(&corner).iter().map(Box::volume).sum::<u128>(), 1000 - 27);
+        assert_eq!(cube.subtract(&corner).len(), 3);
+
+        assert_eq!(cube.subtract(&Box::new([20..=30, 0..=9, 0..=9])), vec![cube.clone()]);
+        assert!(cube.subtract(&cube).is_empty());
+    }
+
+
+    fn small_box() -> impl Strategy<Value = Box<2, i8>> {
+        [(-6i8..6, -6i8..6), (-6i8..6, -6i8..6)].prop_map(|[x, y]| Box::new([x.0..=x.1, y.0..=y.1]))
+    }
+
+    proptest! {
+        #[test]
+        fn test_subtract_matches_points(a in small_box(), b in small_box()) {
+            let pieces = a.subtract(&b);
+            prop_assert!(pieces.len() <= 4);
+            prop_assert!(pieces.iter().all(|piece| !piece.is_empty()));
+
+            for x in -6..6 {
+                for y in -6..6 {
+                    let holders = pieces.iter().filter(|piece| piece.contains(&[x, y])).count();
+                    prop_assert_eq!(holders, usize::from(a.contains(&[x, y]) && !b.contains(&[x, y])));
+                }
+            }
+
+            prop_assert_eq!(pieces.iter().map(Box::volume).sum::<u128>(), a.volume() - a.intersection_volume(&b));
+        }
+    }
+}
@@ -0,0 +1,152 @@
+/// Position of a cell in a [`Grid`], counted from the top left corner.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Coordinate {
+    pub row: usize,
+    pub column: usize
+}
+
+
+/// Dense two dimensional grid stored in row-major order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    rows: usize,
+    columns: usize,
+    data: Vec<T>
+}
+
+
+impl std::fmt::Debug for Coordinate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.row, self.column)
+    }
+}
+
+impl From<(usize, usize)> for Coordinate {
+    fn from(value: (usize, usize)) -> Self {
+        Self {
+            row: value.0,
+            column: value.1
+        }
+    }
+}
+
+impl Coordinate {
+    /// The coordinate `distance` steps away in direction `(row, column)`, or `None` when
+    /// that would leave the non-negative quadrant.
+    pub fn offset(self, (row, column): (isize, isize), distance: isize) -> Option<Coordinate> {
+        Some(Coordinate {
+            row: self.row.checked_add_signed(row.checked_mul(distance)?)?,
+            column: self.column.checked_add_signed(column.checked_mul(distance)?)?
+        })
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Default + Clone
+{
+    /// Creates a grid with every cell set to `T::default()`.
+    pub fn new(rows: usize, columns: usize) -> Self {
+        Self {
+            rows,
+            columns,
+            data: vec![T::default(); rows * columns]
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+
+    pub fn in_bounds(&self, coordinate: Coordinate) -> bool {
+        coordinate.row < self.rows && coordinate.column < self.columns
+    }
+
+
+    pub fn get_cell(&self, coordinate: Coordinate) -> Option<&T> {
+        if !self.in_bounds(coordinate) {
+            return None;
+        }
+
+        Some(&self.data[coordinate.row * self.columns + coordinate.column])
+    }
+
+
+    pub fn get_cell_mut(&mut self, coordinate: Coordinate) -> Option<&mut T> {
+        if !self.in_bounds(coordinate) {
+            return None;
+        }
+
+        Some(&mut self.data[coordinate.row * self.columns + coordinate.column])
+    }
+
+
+    /// Every coordinate of the grid, row by row.
+    pub fn coordinates(&self) -> impl Iterator<Item = Coordinate> + Clone + use<T> {
+        let columns = self.columns;
+
+        (0..self.rows).flat_map(move |row| (0..columns).map(move |column| Coordinate { row, column }))
+    }
+
+
+    /// Cells starting next to `from` and walking in `direction` until the edge of the grid.
+    pub fn ray(&self, from: Coordinate, direction: (isize, isize)) -> impl Iterator<Item = &T> {
+        (1..).map_while(move |distance| self.get_cell(from.offset(direction, distance)?))
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use crate::{Coordinate, Grid};
+
+    fn grid() -> Grid<u8> {
+        let mut grid = Grid::new(2, 3);
+
+        for (value, coordinate) in grid.coordinates().enumerate() {
+            *grid.get_cell_mut(coordinate).unwrap() = value as u8;
+        }
+
+        grid
+    }
+
+
+    #[test]
+    fn test_cells() {
+        let grid = grid();
+
+        assert_eq!((grid.rows(), grid.columns()), (2, 3));
+        assert_eq!(grid.get_cell((1, 2).into()), Some(&5));
+        assert_eq!(grid.get_cell((2, 0).into()), None);
+        assert_eq!(grid.get_cell((0, 3).into()), None);
+        assert!(grid.in_bounds((1, 0).into()));
+    }
+
+
+    #[test]
+    fn test_coordinates() {
+        let coordinates: Vec<Coordinate> = grid().coordinates().collect();
+
+        assert_eq!(coordinates.len(), 6);
+        assert_eq!(coordinates[4], Coordinate { row: 1, column: 1 });
+    }
+
+
+    #[test]
+    fn test_ray() {
+        let grid = grid();
+
+        assert_eq!(grid.ray((0, 0).into(), (0, 1)).copied().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(grid.ray((1, 2).into(), (-1, 0)).copied().collect::<Vec<_>>(), vec![2]);
+        assert_eq!(grid.ray((0, 0).into(), (-1, 0)).count(), 0);
+        assert_eq!(Coordinate::from((0, 0)).offset((0, -1), 1), None);
+    }
+}
@@ -0,0 +1,111 @@
+use std::{fmt, ops::{Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive}};
+
+
+/// A range with an explicit kind of bound at each end.
+///
+/// Unlike the standard ranges any mix is possible, like the left-open `(2, 5]`. Every
+/// standard range converts into an interval, and the results of
+/// [`RangeInclusiveExtension`](crate::RangeInclusiveExtension) operations on intervals
+/// come back with included bounds.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: Bound<T>,
+    pub end: Bound<T>
+}
+
+
+impl<T> Interval<T> {
+    pub fn new(start: Bound<T>, end: Bound<T>) -> Self {
+        Interval { start, end }
+    }
+
+
+    /// Every value there is.
+    pub fn unbounded() -> Self {
+        Interval::new(Bound::Unbounded, Bound::Unbounded)
+    }
+
+
+    /// The values strictly between `start` and `end`.
+    pub fn open(start: T, end: T) -> Self {
+        Interval::new(Bound::Excluded(start), Bound::Excluded(end))
+    }
+
+
+    /// The values from `start` to `end`, both included.
+    pub fn closed(start: T, end: T) -> Self {
+        Interval::new(Bound::Included(start), Bound::Included(end))
+    }
+}
+
+
+impl<T> RangeBounds<T> for Interval<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        self.start.as_ref()
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        self.end.as_ref()
+    }
+}
+
+
+/// Writes intervals the mathematical way, `[2, 5)` or `(-∞, 3]`.
+impl<T: fmt::Debug> fmt::Debug for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.start {
+            Bound::Included(start) => write!(f, "[{start:?}, ")?,
+            Bound::Excluded(start) => write!(f, "({start:?}, ")?,
+            Bound::Unbounded => write!(f, "(-∞, ")?
+        }
+
+        match &self.end {
+            Bound::Included(end) => write!(f, "{end:?}]"),
+            Bound::Excluded(end) => write!(f, "{end:?})"),
+            Bound::Unbounded => write!(f, "∞)")
+        }
+    }
+}
+
+
+impl<T> From<RangeInclusive<T>> for Interval<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        Interval::closed(start, end)
+    }
+}
+
+
+impl<T> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Interval::new(Bound::Included(range.start), Bound::Excluded(range.end))
+    }
+}
+
+
+impl<T> From<RangeFrom<T>> for Interval<T> {
+    fn from(range: RangeFrom<T>) -> Self {
+        Interval::new(Bound::Included(range.start), Bound::Unbounded)
+    }
+}
+
+
+impl<T> From<RangeTo<T>> for Interval<T> {
+    fn from(range: RangeTo<T>) -> Self {
+        Interval::new(Bound::Unbounded, Bound::Excluded(range.end))
+    }
+}
+
+
+impl<T> From<RangeToInclusive<T>> for Interval<T> {
+    fn from(range: RangeToInclusive<T>) -> Self {
+        Interval::new(Bound::Unbounded, Bound::Included(range.end))
+    }
+}
+
+
+impl<T> From<RangeFull> for Interval<T> {
+    fn from(_: RangeFull) -> Self {
+        Interval::unbounded()
+    }
+}
@@ -0,0 +1,244 @@
+use std::{collections::BTreeMap, ops::RangeInclusive};
+
+use crate::{Integer, RangeInclusiveExtension};
+
+
+/// A set of integers stored as the sorted, disjoint and non-adjacent ranges covering them.
+///
+/// Inserted ranges are merged with every range they overlap or touch, so membership
+/// queries only ever have to look at the closest range starting at or before a value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    // range starts to range ends
+    ranges: BTreeMap<T, T>
+}
+
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { ranges: BTreeMap::new() }
+    }
+
+
+    /// How many disjoint ranges make up the set.
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+
+    /// The disjoint ranges in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|(&start, &end)| start..=end)
+    }
+
+
+    /// The stored range holding `value`, if any.
+    fn range_containing(&self, value: T) -> Option<RangeInclusive<T>> {
+        self.ranges.range(..=value)
+            .next_back()
+            .filter(|&(_, &end)| end >= value)
+            .map(|(&start, &end)| start..=end)
+    }
+
+
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let (mut start, mut end) = range.into_inner();
+
+        if let Some((&previous_start, &previous_end)) = self.ranges.range(..=start).next_back()
+            && previous_end.successor().is_none_or(|after| after >= start)
+        {
+            start = previous_start;
+            end = end.max(previous_end);
+        }
+
+        // ranges are never adjacent, so only those starting up to right after `end` can merge
+        let last_start = end.successor();
+        let merged: Vec<T> = self.ranges.range(start..)
+            .take_while(|&(&next_start, _)| last_start.is_none_or(|last_start| next_start <= last_start))
+            .map(|(&next_start, _)| next_start)
+            .collect();
+
+        for next_start in merged {
+            if let Some(next_end) = self.ranges.remove(&next_start) {
+                end = end.max(next_end);
+            }
+        }
+
+        self.ranges.insert(start, end);
+    }
+
+
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let first_start = self.range_containing(*range.start())
+            .map_or(*range.start(), |first| *first.start());
+        let overlapping: Vec<RangeInclusive<T>> = self.ranges.range(first_start..=*range.end())
+            .map(|(&start, &end)| start..=end)
+            .collect();
+
+        for stored in overlapping {
+            self.ranges.remove(stored.start());
+
+            let (below, above) = stored.difference(&range);
+            for part in below.into_iter().chain(above) {
+                self.ranges.insert(*part.start(), *part.end());
+            }
+        }
+    }
+
+
+    /// Adds every range of `other` to the set.
+    pub fn merge(&mut self, other: &IntervalSet<T>) {
+        self.extend(other.iter());
+    }
+
+
+    /// How many values the set holds, saturating at `u128::MAX`.
+    pub fn coverage(&self) -> u128 {
+        self.iter().fold(0, |coverage: u128, range| coverage.saturating_add(range.length()))
+    }
+
+
+    /// The ranges of values missing between the first and the last range of the set.
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter()
+            .zip(self.ranges.keys().skip(1))
+            .filter_map(|((_, &end), &next_start)| Some(end.successor()?..=next_start.predecessor()?))
+    }
+
+
+    pub fn contains(&self, value: T) -> bool {
+        self.range_containing(value).is_some()
+    }
+
+
+    /// Whether every value of `range` is in the set.
+    pub fn contains_range(&self, range: &RangeInclusive<T>) -> bool {
+        range.is_empty() || self.range_containing(*range.start()).is_some_and(|stored| stored.contains_range(range))
+    }
+}
+
+
+impl<T: Integer> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet::new()
+    }
+}
+
+
+impl<T: Integer> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(ranges);
+        set
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use std::{collections::BTreeSet, ops::RangeInclusive};
+
+    use proptest::prelude::*;
+
+    use crate::IntervalSet;
+
+    #[test]
+    fn test_insert_merges() {
+        let set: IntervalSet<i32> = [5..=7, 1..=2, 10..=12, 3..=3, 11..=15].into_iter().collect();
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![1..=3, 5..=7, 10..=15]);
+
+        let mut set = set;
+        set.insert(4..=9);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![1..=15]);
+
+        let set: IntervalSet<u8> = [250..=u8::MAX, 0..=3, 4..=249].into_iter().collect();
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0..=u8::MAX]);
+        assert_eq!(set.coverage(), 256);
+    }
+
+
+    #[test]
+    fn test_remove_splits() {
+        let mut set: IntervalSet<i32> = [1..=10, 20..=30].into_iter().collect();
+
+        set.remove(4..=6);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![1..=3, 7..=10, 20..=30]);
+
+        set.remove(9..=25);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![1..=3, 7..=8, 26..=30]);
+
+        set.remove(0..=100);
+        assert!(set.is_empty());
+    }
+
+
+    #[test]
+    fn test_queries() {
+        let mut set: IntervalSet<i64> = [-5..=-1, 3..=4].into_iter().collect();
+        set.merge(&[10..=10].into_iter().collect());
+
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.coverage(), 8);
+        assert_eq!(set.gaps().collect::<Vec<_>>(), vec![0..=2, 5..=9]);
+
+        assert!(set.contains(-5));
+        assert!(!set.contains(0));
+        assert!(set.contains(10));
+        assert!(set.contains_range(&(-4..=-2)));
+        assert!(!set.contains_range(&(-1..=3)));
+        assert!(!set.contains_range(&(11..=12)));
+    }
+
+
+    proptest! {
+        #[test]
+        fn test_matches_value_set(changes in prop::collection::vec((any::<bool>(), any::<(i8, i8)>()), 0..20), value in any::<i8>()) {
+            let mut set = IntervalSet::new();
+            let mut model = BTreeSet::new();
+
+            for (insert, (start, end)) in changes {
+                if insert {
+                    set.insert(start..=end);
+                    model.extend(start..=end);
+                } else {
+                    set.remove(start..=end);
+                    model.retain(|value| !(start..=end).contains(value));
+                }
+            }
+
+            let ranges: Vec<RangeInclusive<i8>> = set.iter().collect();
+            prop_assert_eq!(ranges.iter().flat_map(|range| range.clone()).collect::<BTreeSet<i8>>(), model.clone());
+            prop_assert!(ranges.windows(2).all(|pair| i16::from(*pair[0].end()) + 1 < i16::from(*pair[1].start())));
+
+            prop_assert_eq!(set.coverage(), model.len() as u128);
+            prop_assert_eq!(set.contains(value), model.contains(&value));
+            prop_assert_eq!(set.contains_range(&(value..=value.saturating_add(3))), (value..=value.saturating_add(3)).all(|value| model.contains(&value)));
+
+            let gaps: BTreeSet<i8> = set.gaps().flatten().collect();
+            let span: BTreeSet<i8> = model.first().zip(model.last()).map(|(&first, &last)| (first..=last).collect()).unwrap_or_default();
+            prop_assert_eq!(gaps, span.difference(&model).copied().collect::<BTreeSet<i8>>());
+        }
+    }
+}
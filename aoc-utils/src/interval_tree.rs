@@ -0,0 +1,168 @@
+use std::ops::RangeInclusive;
+
+use crate::{Integer, RangeInclusiveExtension};
+
+
+/// An immutable interval tree answering which stored ranges hold a value or overlap a range.
+///
+/// The ranges are kept sorted by start in an array that is read as a balanced binary
+/// tree, the middle of every slice being the root of that slice. Each node remembers
+/// the largest end in its subtree, so queries skip every subtree ending before them
+/// and run in `O(log n + k)` for `k` matches.
+///
+/// Every range carries a value, like the line it was read from, to tell apart equal ranges.
+#[derive(Debug, Clone)]
+pub struct IntervalTree<T, V> {
+    entries: Vec<(RangeInclusive<T>, V)>,
+    // the largest end of the subtree rooted at each entry
+    max_ends: Vec<T>
+}
+
+
+impl<T: Integer, V> IntervalTree<T, V> {
+    /// Builds the tree, leaving out empty ranges since nothing can overlap them.
+    pub fn new(entries: impl IntoIterator<Item = (RangeInclusive<T>, V)>) -> Self {
+        let mut entries: Vec<_> = entries.into_iter()
+            .filter(|(range, _)| !range.is_empty())
+            .collect();
+        entries.sort_by_key(|(range, _)| (*range.start(), *range.end()));
+
+        let max_ends = entries.iter().map(|(range, _)| *range.end()).collect();
+        let mut tree = IntervalTree { entries, max_ends };
+        tree.compute_max_ends(0, tree.entries.len());
+
+        tree
+    }
+
+
+    fn compute_max_ends(&mut self, low: usize, high: usize) -> Option<T> {
+        if low >= high {
+            return None;
+        }
+
+        let middle = low + (high - low) / 2;
+        let left = self.compute_max_ends(low, middle);
+        let right = self.compute_max_ends(middle + 1, high);
+
+        let max_end = [left, right].into_iter().flatten().fold(self.max_ends[middle], T::max);
+        self.max_ends[middle] = max_end;
+
+        Some(max_end)
+    }
+
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+
+    /// Every stored range and its value, sorted by start.
+    pub fn iter(&self) -> impl Iterator<Item = (&RangeInclusive<T>, &V)> {
+        self.entries.iter().map(|(range, value)| (range, value))
+    }
+
+
+    /// The stored ranges holding `value`, sorted by start.
+    pub fn containing(&self, value: T) -> Vec<(&RangeInclusive<T>, &V)> {
+        self.overlapping(&(value..=value))
+    }
+
+
+    /// The stored ranges sharing at least one value with `range`, sorted by start.
+    pub fn overlapping(&self, range: &RangeInclusive<T>) -> Vec<(&RangeInclusive<T>, &V)> {
+        let mut found = Vec::new();
+
+        if !range.is_empty() {
+            self.collect_overlapping(range, 0, self.entries.len(), &mut found);
+        }
+
+        found
+    }
+
+
+    fn collect_overlapping<'t>(&'t self, range: &RangeInclusive<T>, low: usize, high: usize, found: &mut Vec<(&'t RangeInclusive<T>, &'t V)>) {
+        if low >= high {
+            return;
+        }
+
+        let middle = low + (high - low) / 2;
+
+        if self.max_ends[middle] < *range.start() {
+            return;
+        }
+
+        self.collect_overlapping(range, low, middle, found);
+
+        let (stored, value) = &self.entries[middle];
+
+        // everything from here on starts after the end of the query
+        if stored.start() > range.end() {
+            return;
+        }
+
+        if stored.overlaps_or_is_overlapped(range) {
+            found.push((stored, value));
+        }
+
+        self.collect_overlapping(range, middle + 1, high, found);
+    }
+}
+
+
+impl<T: Integer, V> FromIterator<(RangeInclusive<T>, V)> for IntervalTree<T, V> {
+    fn from_iter<I: IntoIterator<Item = (RangeInclusive<T>, V)>>(entries: I) -> Self {
+        IntervalTree::new(entries)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use std::ops::RangeInclusive;
+
+    use crate::IntervalTree;
+
+    fn values<'t>(found: Vec<(&'t RangeInclusive<u32>, &'t usize)>) -> Vec<usize> {
+        found.into_iter().map(|(_, &value)| value).collect()
+    }
+
+
+    #[test]
+    fn test_queries() {
+        // the elves of the day 4 example, numbered by line
+        let assignments = [2..=4, 6..=8, 2..=3, 4..=5, 5..=7, 7..=9, 2..=8, 3..=7, 6..=6, 4..=6, 2..=6, 4..=8];
+        let tree: IntervalTree<u32, usize> = assignments.into_iter().zip(0..).collect();
+
+        assert_eq!(tree.len(), 12);
+        assert!(tree.containing(1).is_empty());
+        assert_eq!(values(tree.containing(9)), vec![5]);
+        assert_eq!(values(tree.containing(6)), vec![10, 6, 7, 9, 11, 4, 8, 1]);
+        assert_eq!(values(tree.overlapping(&(8..=20))), vec![6, 11, 1, 5]);
+        assert!(tree.overlapping(&(0..=1)).is_empty());
+    }
+
+
+    #[test]
+    fn test_matches_linear_scan() {
+        let ranges: Vec<_> = (0..60u32).map(|index| (index * 7 % 23)..=(index * 7 % 23 + index % 5)).collect();
+        let tree: IntervalTree<u32, usize> = ranges.iter().cloned().zip(0..).collect();
+
+        for start in 0..30 {
+            for end in start..30 {
+                let mut expected: Vec<usize> = (0..ranges.len())
+                    .filter(|&index| *ranges[index].start() <= end && start <= *ranges[index].end())
+                    .collect();
+                let mut found = values(tree.overlapping(&(start..=end)));
+
+                expected.sort();
+                found.sort();
+                assert_eq!(found, expected);
+            }
+        }
+    }
+}
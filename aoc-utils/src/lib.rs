@@ -0,0 +1,17 @@
+//! Building blocks shared between puzzles of every year.
+
+mod cuboid;
+mod grid;
+mod interval;
+mod interval_set;
+mod interval_tree;
+mod parse;
+mod range;
+
+pub use cuboid::Box;
+pub use grid::{Coordinate, Grid};
+pub use interval::Interval;
+pub use interval_set::IntervalSet;
+pub use interval_tree::IntervalTree;
+pub use parse::{number, signed_number};
+pub use range::{Integer, IntegerRange, RangeInclusiveExtension, RangeParts};
@@ -0,0 +1,68 @@
+use std::str::FromStr;
+
+use nom::{IResult, Parser, character::complete::{digit1, one_of}, combinator::{map_res, opt, recognize}, error::{ContextError, FromExternalError, ParseError, context}};
+
+
+/// Parses an unsigned decimal number into any type that can be parsed from a string.
+///
+/// Generic over the error type, so it works both with nom's default error and with
+/// `aoc_core::ParseFailure`, where a missing number is reported as "expected a number".
+pub fn number<'a, T, E>(input: &'a str) -> IResult<&'a str, T, E>
+where
+    T: FromStr,
+    E: ParseError<&'a str> + ContextError<&'a str> + FromExternalError<&'a str, T::Err>
+{
+    map_res(context("a number", digit1), str::parse::<T>).parse(input)
+}
+
+
+/// Like [`number`] but also accepts a leading `+` or `-`.
+pub fn signed_number<'a, T, E>(input: &'a str) -> IResult<&'a str, T, E>
+where
+    T: FromStr,
+    E: ParseError<&'a str> + ContextError<&'a str> + FromExternalError<&'a str, T::Err>
+{
+    let digits = recognize((opt(one_of("+-")), digit1));
+
+    map_res(context("a number", digits), str::parse::<T>).parse(input)
+}
+
+
+#[cfg(test)]
+mod test {
+    use aoc_core::{ParseFailure, parse_input_line};
+    use nom::IResult;
+    use crate::{number, signed_number};
+
+    #[test]
+    fn test_number() {
+        let parsed: IResult<&str, usize> = number("123 from");
+        assert_eq!(parsed, Ok((" from", 123)));
+
+        let parsed: IResult<&str, u8> = number("256");
+        assert!(parsed.is_err());
+
+        let parsed: IResult<&str, u64> = number("-1");
+        assert!(parsed.is_err());
+    }
+
+
+    #[test]
+    fn test_signed_number() {
+        let parsed: IResult<&str, i32> = signed_number("-42,");
+        assert_eq!(parsed, Ok((",", -42)));
+
+        let parsed: IResult<&str, i32> = signed_number("+7");
+        assert_eq!(parsed, Ok(("", 7)));
+    }
+
+
+    #[test]
+    fn test_diagnostics() {
+        let error = parse_input_line(number::<u32, ParseFailure>, "x1", 4).unwrap_err();
+        assert_eq!(error.to_string(), "line 4, column 1: expected a number");
+
+        let error = parse_input_line(number::<u8, ParseFailure>, "300", 1).unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 1: number too large to fit in target type");
+    }
+}
@@ -0,0 +1,472 @@
+use std::ops::{Bound, Range, RangeBounds, RangeFrom, RangeInclusive, RangeTo};
+
+use crate::Interval;
+
+
+/// Primitive integers, the values interval arithmetic works on.
+pub trait Integer: Copy + Ord {
+    const MIN: Self;
+    const MAX: Self;
+
+    /// The next value, unless `self` is the largest one.
+    fn successor(self) -> Option<Self>;
+
+    /// The previous value, unless `self` is the smallest one.
+    fn predecessor(self) -> Option<Self>;
+
+    /// How many steps `end` is above `self`, `end` must not be smaller than `self`.
+    fn distance(self, end: Self) -> u128;
+}
+
+
+macro_rules! impl_integer {
+    ($($integer:ty),* ; $($signed:ty),*) => {
+        $(
+            impl Integer for $integer {
+                const MIN: Self = <$integer>::MIN;
+                const MAX: Self = <$integer>::MAX;
+
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn distance(self, end: Self) -> u128 {
+                    (end - self) as u128
+                }
+            }
+        )*
+        $(
+            impl Integer for $signed {
+                const MIN: Self = <$signed>::MIN;
+                const MAX: Self = <$signed>::MAX;
+
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                // the difference always fits the unsigned type of the same width
+                fn distance(self, end: Self) -> u128 {
+                    (end as i128).wrapping_sub(self as i128) as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize; i8, i16, i32, i64, i128, isize);
+
+
+/// A kind of range over integers, whatever its bounds.
+///
+/// Every such range holds the same values as an inclusive range, which is what the
+/// [`RangeInclusiveExtension`] operations work on before converting back.
+pub trait IntegerRange: RangeBounds<Self::Value> + Sized {
+    type Value: Integer;
+
+    /// The range of this kind holding exactly the values of `range`, if there is one.
+    fn from_inclusive(range: RangeInclusive<Self::Value>) -> Option<Self>;
+
+    /// The first and the last value of the range, unless it is empty.
+    fn to_inclusive(&self) -> Option<RangeInclusive<Self::Value>> {
+        let start = match self.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.successor()?,
+            Bound::Unbounded => Self::Value::MIN
+        };
+
+        let end = match self.end_bound() {
+            Bound::Included(&end) => end,
+            Bound::Excluded(&end) => end.predecessor()?,
+            Bound::Unbounded => Self::Value::MAX
+        };
+
+        (start <= end).then_some(start..=end)
+    }
+}
+
+impl<T: Integer> IntegerRange for RangeInclusive<T> {
+    type Value = T;
+
+    fn from_inclusive(range: RangeInclusive<T>) -> Option<Self> {
+        Some(range)
+    }
+}
+
+impl<T: Integer> IntegerRange for Range<T> {
+    type Value = T;
+
+    fn from_inclusive(range: RangeInclusive<T>) -> Option<Self> {
+        let (start, end) = range.into_inner();
+        Some(start..end.successor()?)
+    }
+}
+
+impl<T: Integer> IntegerRange for RangeFrom<T> {
+    type Value = T;
+
+    fn from_inclusive(range: RangeInclusive<T>) -> Option<Self> {
+        let (start, end) = range.into_inner();
+        (end == T::MAX).then_some(start..)
+    }
+}
+
+impl<T: Integer> IntegerRange for RangeTo<T> {
+    type Value = T;
+
+    fn from_inclusive(range: RangeInclusive<T>) -> Option<Self> {
+        let (start, end) = range.into_inner();
+        (start == T::MIN).then_some(..end.successor()?)
+    }
+}
+
+impl<T: Integer> IntegerRange for Interval<T> {
+    type Value = T;
+
+    fn from_inclusive(range: RangeInclusive<T>) -> Option<Self> {
+        Some(range.into())
+    }
+}
+
+
+/// What is left of a range after taking another one out, below and above it.
+pub type RangeParts<T> = (Option<RangeInclusive<T>>, Option<RangeInclusive<T>>);
+
+
+/// Set-like comparisons and arithmetic between ranges of the same kind.
+///
+/// Empty ranges, like `5..=4` or `3..3`, hold no values: they intersect nothing,
+/// merge into any range and have a length of 0.
+pub trait RangeInclusiveExtension: IntegerRange {
+    /// Whether every value of `other` is also in `self`.
+    fn contains_range(&self, other: &Self) -> bool {
+        let Some(other) = other.to_inclusive() else {
+            return true;
+        };
+
+        self.to_inclusive().is_some_and(|range| range.start() <= other.start() && other.end() <= range.end())
+    }
+
+    /// Whether `self` contains either end of `other`.
+    ///
+    /// This alone misses the case where `other` strictly contains `self`, use
+    /// [`RangeInclusiveExtension::overlaps_or_is_overlapped`] for a symmetric check.
+    fn overlaps(&self, other: &Self) -> bool {
+        other.to_inclusive().is_some_and(|other| self.contains(other.start()) || self.contains(other.end()))
+    }
+
+    fn contains_or_is_contained(&self, other: &Self) -> bool {
+        self.contains_range(other) || other.contains_range(self)
+    }
+
+    /// Whether the two ranges share at least one value.
+    fn overlaps_or_is_overlapped(&self, other: &Self) -> bool {
+        self.overlaps(other) || other.overlaps(self)
+    }
+
+    /// Whether one range ends right before the other starts.
+    fn is_adjacent(&self, other: &Self) -> bool {
+        let (Some(range), Some(other)) = (self.to_inclusive(), other.to_inclusive()) else {
+            return false;
+        };
+
+        range.end().successor() == Some(*other.start()) || other.end().successor() == Some(*range.start())
+    }
+
+    /// The values in both ranges.
+    fn intersection(&self, other: &Self) -> Option<Self> {
+        let (range, other) = (self.to_inclusive()?, other.to_inclusive()?);
+        let intersection = *range.start().max(other.start())..=*range.end().min(other.end());
+
+        if intersection.is_empty() {
+            return None;
+        }
+
+        Self::from_inclusive(intersection)
+    }
+
+    /// The values in either range, when together they form a single range of this kind.
+    fn union(&self, other: &Self) -> Option<Self> {
+        let (range, other) = match (self.to_inclusive(), other.to_inclusive()) {
+            (Some(range), Some(other)) => (range, other),
+            (Some(range), None) | (None, Some(range)) => return Self::from_inclusive(range),
+            (None, None) => return None
+        };
+
+        let overlapping = range.start() <= other.end() && other.start() <= range.end();
+        let adjacent = range.end().successor() == Some(*other.start()) || other.end().successor() == Some(*range.start());
+
+        if !overlapping && !adjacent {
+            return None;
+        }
+
+        Self::from_inclusive(*range.start().min(other.start())..=*range.end().max(other.end()))
+    }
+
+    /// The values of `self` that are not in `other`, as the parts below and above `other`.
+    ///
+    /// Taking out an empty range leaves all of `self` below it.
+    ///
+    /// The parts are inclusive ranges, since what is left of a half-open or unbounded
+    /// range is not always a range of the same kind.
+    fn difference(&self, other: &Self) -> RangeParts<Self::Value> {
+        let Some(range) = self.to_inclusive() else {
+            return (None, None);
+        };
+
+        let Some(other) = other.to_inclusive() else {
+            return (Some(range), None);
+        };
+
+        let overlap = *range.start().max(other.start())..=*range.end().min(other.end());
+
+        if overlap.is_empty() {
+            return if range.end() < other.start() { (Some(range), None) } else { (None, Some(range)) };
+        }
+
+        let below = overlap.start().predecessor()
+            .map(|end| *range.start()..=end)
+            .filter(|below| !below.is_empty());
+        let above = overlap.end().successor()
+            .map(|start| start..=*range.end())
+            .filter(|above| !above.is_empty());
+
+        (below, above)
+    }
+
+    /// How many values the range holds, saturating at `u128::MAX` for the full
+    /// `u128` and `i128` ranges.
+    fn length(&self) -> u128 {
+        self.to_inclusive()
+            .map_or(0, |range| range.start().distance(*range.end()).saturating_add(1))
+    }
+}
+
+impl<R: IntegerRange> RangeInclusiveExtension for R {}
+
+
+#[cfg(test)]
+mod test {
+    use std::{collections::BTreeSet, fmt::Debug, ops::{Bound, RangeInclusive}};
+
+    use proptest::{prelude::*, test_runner::TestCaseError};
+
+    use crate::{Interval, IntegerRange, RangeInclusiveExtension};
+
+    #[test]
+    fn test_contains() {
+        assert!((2..=8).contains_range(&(3..=7)));
+        assert!(!(3..=7).contains_range(&(2..=8)));
+        assert!((3..=7).contains_or_is_contained(&(2..=8)));
+        assert!((6..=6).contains_or_is_contained(&(4..=6)));
+        assert!(!(2..=4).contains_or_is_contained(&(6..=8)));
+    }
+
+
+    #[test]
+    fn test_overlaps() {
+        assert!((5..=7).overlaps(&(7..=9)));
+        assert!(!(3..=7).overlaps(&(2..=8)));
+        assert!((3..=7).overlaps_or_is_overlapped(&(2..=8)));
+        assert!(!(2..=3).overlaps_or_is_overlapped(&(4..=5)));
+    }
+
+
+    #[test]
+    fn test_adjacent() {
+        assert!((2..=3).is_adjacent(&(4..=5)));
+        assert!((4..=5).is_adjacent(&(2..=3)));
+        assert!(!(2..=3).is_adjacent(&(3..=5)));
+        assert!(!(2..=3).is_adjacent(&(5..=6)));
+        assert!(!(u8::MAX..=u8::MAX).is_adjacent(&(0..=0)));
+    }
+
+
+    #[test]
+    fn test_intersection() {
+        assert_eq!((2..=6).intersection(&(4..=8)), Some(4..=6));
+        assert_eq!((2..=8).intersection(&(3..=7)), Some(3..=7));
+        assert_eq!((5..=7).intersection(&(7..=9)), Some(7..=7));
+        assert_eq!((2..=4).intersection(&(6..=8)), None);
+        assert_eq!((2..=8).intersection(&RangeInclusive::new(5, 4)), None);
+    }
+
+
+    #[test]
+    fn test_union() {
+        assert_eq!((2..=6).union(&(4..=8)), Some(2..=8));
+        assert_eq!((2..=3).union(&(4..=8)), Some(2..=8));
+        assert_eq!((2..=3).union(&(5..=8)), None);
+        assert_eq!((2..=3).union(&RangeInclusive::new(9, 8)), Some(2..=3));
+        assert_eq!((i64::MIN..=-1).union(&(0..=i64::MAX)), Some(i64::MIN..=i64::MAX));
+    }
+
+
+    #[test]
+    fn test_difference() {
+        assert_eq!((2..=8).difference(&(4..=6)), (Some(2..=3), Some(7..=8)));
+        assert_eq!((2..=8).difference(&(0..=6)), (None, Some(7..=8)));
+        assert_eq!((2..=8).difference(&(6..=9)), (Some(2..=5), None));
+        assert_eq!((2..=8).difference(&(1..=9)), (None, None));
+        assert_eq!((2..=4).difference(&(6..=9)), (Some(2..=4), None));
+        assert_eq!((6..=9).difference(&(2..=4)), (None, Some(6..=9)));
+        assert_eq!((0..=u32::MAX).difference(&(0..=u32::MAX)), (None, None));
+    }
+
+
+    #[test]
+    fn test_length() {
+        assert_eq!((2..=8).length(), 7);
+        assert_eq!((3..=3).length(), 1);
+        assert_eq!(RangeInclusive::new(3, 2).length(), 0);
+        assert_eq!((-5i8..=5).length(), 11);
+        assert_eq!((i8::MIN..=i8::MAX).length(), 256);
+        assert_eq!((i64::MIN..=i64::MAX).length(), 1 << 64);
+        assert_eq!((0..=u128::MAX).length(), u128::MAX);
+    }
+
+
+    #[test]
+    fn test_half_open_ranges() {
+        assert!((2..8).contains_range(&(3..8)));
+        assert!(!(2..8).contains_range(&(3..9)));
+        assert!(!(2..5).overlaps_or_is_overlapped(&(5..8)));
+        assert!((2..5).is_adjacent(&(5..8)));
+        assert_eq!((2..6).intersection(&(4..8)), Some(4..6));
+        assert_eq!((2..5).intersection(&(5..8)), None);
+        assert_eq!((2..5).union(&(5..8)), Some(2..8));
+        assert_eq!((2..8).difference(&(4..6)), (Some(2..=3), Some(6..=7)));
+        assert_eq!((3..3).length(), 0);
+        assert_eq!((0..u8::MAX).length(), 255);
+    }
+
+
+    #[test]
+    fn test_unbounded_ranges() {
+        assert!((5u8..).contains_range(&(200..)));
+        assert!((5u8..).overlaps_or_is_overlapped(&(250..)));
+        assert_eq!((5u8..).intersection(&(10..)), Some(10..));
+        assert_eq!((5u8..).length(), 251);
+        assert_eq!((5u8..).difference(&(10..)), (Some(5..=9), None));
+
+        assert!(!(..5i8).overlaps_or_is_overlapped(&(..i8::MIN)));
+        assert_eq!((..5i8).intersection(&(..3)), Some(..3));
+        assert_eq!((..5i8).union(&(..3)), Some(..5));
+        assert_eq!((..0i8).length(), 128);
+        assert_eq!((..5u8).difference(&(..3)), (None, Some(3..=4)));
+    }
+
+
+    #[test]
+    fn test_intervals() {
+        let left_open = Interval::new(Bound::Excluded(2), Bound::Included(5));
+
+        assert!(left_open.contains_range(&Interval::closed(3, 5)));
+        assert!(!left_open.contains_range(&Interval::closed(2, 5)));
+        assert!(!left_open.overlaps_or_is_overlapped(&Interval::closed(0, 2)));
+        assert!(left_open.is_adjacent(&Interval::from(0..3)));
+        assert_eq!(left_open.intersection(&Interval::open(4, 9)), Some(Interval::closed(5, 5)));
+        assert_eq!(left_open.union(&(6..).into()), Some(Interval::closed(3, i32::MAX)));
+        assert_eq!(Interval::open(4, 5).length(), 0);
+        assert_eq!(Interval::<u16>::unbounded().length(), 1 << 16);
+        assert_eq!(Interval::from(..=3u8).difference(&Interval::open(0, 2)), (Some(0..=0), Some(2..=3)));
+        assert_eq!(format!("{:?}", Interval::from(..=3)), "(-∞, 3]");
+    }
+
+
+    // `i8` ranges are small enough to compare every operation against the plain sets of
+    // values they hold, including at the ends of the type where overflows would hide
+
+    fn values<R: IntegerRange<Value = i8>>(range: &R) -> BTreeSet<i8> {
+        (i8::MIN..=i8::MAX).filter(|value| range.contains(value)).collect()
+    }
+
+    fn parts_values(parts: &[Option<RangeInclusive<i8>>]) -> BTreeSet<i8> {
+        parts.iter().flatten().flat_map(values).collect()
+    }
+
+    fn is_contiguous(values: &BTreeSet<i8>) -> bool {
+        match (values.first(), values.last()) {
+            (Some(&first), Some(&last)) => values.len() == (first..=last).count(),
+            _ => false
+        }
+    }
+
+    fn check_laws<R>(a: &R, b: &R) -> Result<(), TestCaseError>
+    where
+        R: RangeInclusiveExtension<Value = i8> + Debug + Clone
+    {
+        let (a_values, b_values) = (values(a), values(b));
+        let common: BTreeSet<i8> = a_values.intersection(&b_values).copied().collect();
+        let either: BTreeSet<i8> = a_values.union(&b_values).copied().collect();
+
+        prop_assert_eq!(a.length(), a_values.len() as u128);
+        prop_assert_eq!(a.contains_range(b), b_values.is_subset(&a_values));
+        prop_assert_eq!(a.contains_or_is_contained(b), b.contains_or_is_contained(a));
+
+        prop_assert_eq!(a.overlaps_or_is_overlapped(b), !common.is_empty());
+        prop_assert_eq!(a.overlaps_or_is_overlapped(b), b.overlaps_or_is_overlapped(a));
+        prop_assert_eq!(
+            a.overlaps(b),
+            [b_values.first(), b_values.last()].into_iter().flatten().any(|end| a_values.contains(end))
+        );
+
+        prop_assert_eq!(a.is_adjacent(b), common.is_empty() && !a_values.is_empty() && !b_values.is_empty() && is_contiguous(&either));
+        prop_assert_eq!(a.is_adjacent(b), b.is_adjacent(a));
+
+        let intersection = a.intersection(b);
+        prop_assert_eq!(intersection.as_ref().map(values).unwrap_or_default(), common.clone());
+        prop_assert_eq!(intersection.is_some(), !common.is_empty());
+
+        match a.union(b) {
+            Some(union) => prop_assert_eq!(values(&union), either),
+            None => prop_assert!(!is_contiguous(&either) || R::from_inclusive(*either.first().unwrap()..=*either.last().unwrap()).is_none())
+        }
+
+        let (below, above) = a.difference(b);
+        prop_assert_eq!(parts_values(&[below.clone(), above.clone()]), a_values.difference(&b_values).copied().collect::<BTreeSet<i8>>());
+        prop_assert!(below.iter().all(|below| !below.is_empty() && b_values.iter().all(|value| value > below.end())));
+        prop_assert!(above.is_none() || !b_values.is_empty());
+        prop_assert!(above.iter().all(|above| !above.is_empty() && b_values.iter().all(|value| value < above.start())));
+
+        Ok(())
+    }
+
+    fn bound() -> impl Strategy<Value = Bound<i8>> {
+        prop_oneof![
+            any::<i8>().prop_map(Bound::Included),
+            any::<i8>().prop_map(Bound::Excluded),
+            Just(Bound::Unbounded)
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn test_inclusive_range_laws(a in any::<(i8, i8)>(), b in any::<(i8, i8)>()) {
+            check_laws(&(a.0..=a.1), &(b.0..=b.1))?;
+        }
+
+        #[test]
+        fn test_half_open_range_laws(a in any::<(i8, i8)>(), b in any::<(i8, i8)>()) {
+            check_laws(&(a.0..a.1), &(b.0..b.1))?;
+        }
+
+        #[test]
+        fn test_unbounded_range_laws(a in any::<i8>(), b in any::<i8>()) {
+            check_laws(&(a..), &(b..))?;
+            check_laws(&(..a), &(..b))?;
+        }
+
+        #[test]
+        fn test_interval_laws(a in (bound(), bound()), b in (bound(), bound())) {
+            check_laws(&Interval::new(a.0, a.1), &Interval::new(b.0, b.1))?;
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use std::{hint::black_box, time::{Duration, Instant}};
+
+use aoc_core::{DynSolution, Result};
+use serde::Serialize;
+
+use crate::table::Table;
+
+
+/// Summary of the timings of one phase across every iteration, in nanoseconds.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub(crate) struct Stats {
+    pub(crate) min: u128,
+    pub(crate) median: u128,
+    pub(crate) p95: u128
+}
+
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "Benchmark needs at least one sample");
+        samples.sort();
+
+        let p95_rank = (samples.len() * 95).div_ceil(100).max(1);
+
+        Self {
+            min: samples[0].as_nanos(),
+            median: samples[(samples.len() - 1) / 2].as_nanos(),
+            p95: samples[p95_rank - 1].as_nanos()
+        }
+    }
+}
+
+
+#[derive(Debug, Serialize)]
+pub(crate) struct DayBenchmark {
+    pub(crate) year: u16,
+    pub(crate) day: u8,
+    pub(crate) iterations: usize,
+    pub(crate) parse: Stats,
+    pub(crate) part1: Stats,
+    pub(crate) part2: Stats
+}
+
+
+/// Times parsing and both parts separately, re-parsing the input on every iteration
+/// so each phase always starts from the same state.
+pub(crate) fn benchmark(year: u16, day: u8, solution: &dyn DynSolution, input: &str, iterations: usize) -> Result<DayBenchmark> {
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part1 = Vec::with_capacity(iterations);
+    let mut part2 = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = solution.parse(black_box(input))?;
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(parsed.part1()?);
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(parsed.part2()?);
+        part2.push(start.elapsed());
+    }
+
+    Ok(DayBenchmark {
+        year,
+        day,
+        iterations,
+        parse: Stats::from_samples(parse),
+        part1: Stats::from_samples(part1),
+        part2: Stats::from_samples(part2)
+    })
+}
+
+
+pub(crate) fn table(benchmarks: &[DayBenchmark]) -> Table {
+    let mut table = Table::new(["year", "day", "phase", "min", "median", "p95"]);
+
+    for benchmark in benchmarks {
+        for (phase, stats) in [("parse", &benchmark.parse), ("part 1", &benchmark.part1), ("part 2", &benchmark.part2)] {
+            table.push(vec![
+                benchmark.year.to_string(),
+                benchmark.day.to_string(),
+                phase.to_string(),
+                format_nanos(stats.min),
+                format_nanos(stats.median),
+                format_nanos(stats.p95)
+            ]);
+        }
+    }
+
+    table
+}
+
+
+fn format_nanos(nanos: u128) -> String {
+    format!("{:.2?}", Duration::from_nanos(nanos.try_into().unwrap_or(u64::MAX)))
+}
+
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+    use crate::bench::Stats;
+
+    #[test]
+    fn test_stats() {
+        let samples = (1..=100).rev().map(Duration::from_nanos).collect();
+        assert_eq!(Stats::from_samples(samples), Stats { min: 1, median: 50, p95: 95 });
+
+        let samples = vec![Duration::from_nanos(7)];
+        assert_eq!(Stats::from_samples(samples), Stats { min: 7, median: 7, p95: 7 });
+
+        let samples = [30, 10, 20].into_iter().map(Duration::from_nanos).collect();
+        assert_eq!(Stats::from_samples(samples), Stats { min: 10, median: 20, p95: 30 });
+    }
+}
@@ -0,0 +1,312 @@
+use std::{env, error::Error, path::{Path, PathBuf}, process::ExitCode};
+
+use aoc_core::{AnswerDatabase, DEFAULT_PROFILE, DirectoryFetcher, DynSolution, Input, InputStore, Part};
+use clap::{Parser, Subcommand};
+
+mod bench;
+mod scaffold;
+mod solutions;
+mod table;
+mod verify;
+
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions runner")]
+struct Cli {
+    /// Input profile, one per Advent of Code account
+    #[arg(long, global = true, env = "AOC_PROFILE", default_value = DEFAULT_PROFILE)]
+    profile: String,
+
+    /// Input cache directory, defaults to `$AOC_CACHE_DIR` or `~/.cache/aoc`
+    #[arg(long, global = true)]
+    cache_dir: Option<PathBuf>,
+
+    /// Recorded answers database, defaults to `answers.json` at the root of the workspace
+    #[arg(long, global = true, env = "AOC_ANSWERS")]
+    answers: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Command
+}
+
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution for a single day
+    Run {
+        year: u16,
+        day: u8,
+
+        /// Run only this part instead of both
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Puzzle input file or `-` for stdin, defaults to the profile's stored input and
+        /// then to the embedded input when the runner is built with the `embedded` feature
+        /// and the profile is `$AOC_EMBEDDED_PROFILE`, or the default one
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Record the results as the profile's known answers
+        #[arg(long)]
+        record: bool
+    },
+
+    /// Re-run solutions and compare them against the recorded answers
+    Verify {
+        year: Option<u16>,
+        day: Option<u8>,
+
+        /// Check the answers of every recorded profile instead of only the selected one
+        #[arg(long)]
+        all_profiles: bool
+    },
+
+    /// Time parsing and both parts of every registered solution that has an input
+    Bench {
+        year: Option<u16>,
+        day: Option<u8>,
+
+        #[arg(long, default_value_t = 100, value_parser = clap::value_parser!(u64).range(1..))]
+        iterations: u64,
+
+        /// Print the timings as JSON, in nanoseconds
+        #[arg(long)]
+        json: bool
+    },
+
+    /// Generate a new day crate and register it with the runner
+    New {
+        year: u16,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8
+    },
+
+    /// Manage the input cache
+    #[command(subcommand)]
+    Input(InputCommand)
+}
+
+
+#[derive(Subcommand)]
+enum InputCommand {
+    /// Copy a local file (or `-` for stdin) into the profile's input cache
+    Import {
+        year: u16,
+        day: u8,
+        path: PathBuf
+    },
+
+    /// Fetch a missing input from a directory laid out like the input cache
+    Fetch {
+        year: u16,
+        day: u8,
+
+        #[arg(long)]
+        from: PathBuf
+    },
+
+    /// Print where the profile's input for a day is stored
+    Path {
+        year: u16,
+        day: u8
+    },
+
+    /// List every profile with stored inputs
+    Profiles
+}
+
+
+impl Cli {
+    /// The answers database, found at the root of the workspace like the crates `aoc new`
+    /// adds, or in the current directory when running outside of it.
+    fn answers(&self) -> PathBuf {
+        self.answers.clone().unwrap_or_else(|| {
+            scaffold::workspace_root().map_or_else(|_| PathBuf::from("answers.json"), |root| root.join("answers.json"))
+        })
+    }
+}
+
+
+/// The profile whose inputs are embedded in the binary, `$AOC_EMBEDDED_PROFILE` or the
+/// default one.
+fn embedded_profile() -> String {
+    env::var("AOC_EMBEDDED_PROFILE").unwrap_or_else(|_| DEFAULT_PROFILE.to_owned())
+}
+
+
+/// The profile's stored input, falling back to the one embedded in the binary only for
+/// the account it belongs to, since every account has its own inputs.
+pub(crate) fn stored_input(store: &InputStore, solution: &dyn DynSolution, year: u16, day: u8) -> Option<Input<'static>> {
+    store.get(year, day).or_else(|| {
+        solution.embedded_input()
+            .filter(|_| store.profile() == embedded_profile())
+            .map(Input::Text)
+    })
+}
+
+
+fn run(store: &InputStore, answers_path: &Path, year: u16, day: u8, part: Option<u8>, input: Option<PathBuf>, record: bool) -> Result<ExitCode, Box<dyn Error>> {
+    let registry = solutions::registry();
+
+    let Some(solution) = registry.get(year, day) else {
+        eprintln!("No solution registered for {year} day {day}");
+        return Ok(ExitCode::FAILURE);
+    };
+
+    let input = match input {
+        Some(path) => Input::from(path),
+        None => stored_input(store, solution, year, day).ok_or_else(|| format!(
+            "No input for {year} day {day} in profile `{}`, import it with `aoc input import {year} {day} <path>`",
+            store.profile()
+        ))?
+    };
+
+    let parsed = solution.load(&input)
+        .map_err(|error| format!("Invalid input for {year} day {day}: {error}"))?;
+
+    let parts = match part {
+        Some(part) => vec![Part::try_from(part)?],
+        None => Part::ALL.to_vec()
+    };
+
+    // the database is only touched when the answers are recorded
+    let mut answers = if record { Some(AnswerDatabase::load(answers_path)?) } else { None };
+
+    for part in parts {
+        let answer = parsed.part(part)
+            .map_err(|error| format!("Part {part} failed: {error}"))?;
+        println!("Part {part} result: {answer}");
+
+        if let Some(answers) = &mut answers {
+            if let Some(expected) = answers.get(store.profile(), year, day, part).filter(|expected| *expected != answer.to_string()) {
+                eprintln!("Part {part} replaces the recorded answer {expected}");
+            }
+
+            answers.record(store.profile(), year, day, part, &answer);
+        }
+    }
+
+    if let Some(answers) = answers {
+        answers.save(answers_path)?;
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+
+fn verify(store: &InputStore, answers_path: &Path, year: Option<u16>, day: Option<u8>, all_profiles: bool) -> Result<ExitCode, Box<dyn Error>> {
+    let answers = AnswerDatabase::load(answers_path)?;
+
+    let profiles = if all_profiles {
+        answers.profiles().map(str::to_owned).collect()
+    } else {
+        vec![store.profile().to_owned()]
+    };
+
+    let filter = verify::Filter { profiles, year, day };
+
+    Ok(verify::verify(&solutions::registry(), &answers, store.root(), &filter))
+}
+
+
+fn bench(store: &InputStore, year: Option<u16>, day: Option<u8>, iterations: u64, json: bool) -> Result<ExitCode, Box<dyn Error>> {
+    let registry = solutions::registry();
+    let mut benchmarks = Vec::new();
+
+    for ((solution_year, solution_day), solution) in registry.iter() {
+        if year.is_some_and(|year| year != solution_year) || day.is_some_and(|day| day != solution_day) {
+            continue;
+        }
+
+        let Some(input) = stored_input(store, solution, solution_year, solution_day) else {
+            eprintln!("Skipping {solution_year} day {solution_day}, no input in profile `{}`", store.profile());
+            continue;
+        };
+
+        let input = input.read()?;
+        let benchmark = bench::benchmark(solution_year, solution_day, solution, &input, iterations as usize)
+            .map_err(|error| format!("{solution_year} day {solution_day}: {error}"))?;
+        benchmarks.push(benchmark);
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&benchmarks)?);
+    } else {
+        print!("{}", bench::table(&benchmarks));
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+
+fn new(profile: &str, answers_path: &Path, year: u16, day: u8) -> Result<ExitCode, Box<dyn Error>> {
+    let scaffold = scaffold::Scaffold { year, day };
+    let directory = scaffold::generate(&scaffold::workspace_root()?, &scaffold)?;
+
+    let mut answers = AnswerDatabase::load(answers_path)?;
+    answers.add_day(profile, year, day);
+    answers.save(answers_path)?;
+
+    println!("Created {} in {}", scaffold.crate_name(), directory.display());
+
+    Ok(ExitCode::SUCCESS)
+}
+
+
+fn input(store: &InputStore, command: InputCommand) -> Result<ExitCode, Box<dyn Error>> {
+    match command {
+        InputCommand::Import { year, day, path } => {
+            let path = store.import(year, day, &Input::from(path))?;
+            println!("Stored input at {}", path.display());
+        }
+        InputCommand::Fetch { year, day, from } => {
+            let path = store.fetch(year, day, &DirectoryFetcher::new(from))?;
+            println!("Stored input at {}", path.display());
+        }
+        InputCommand::Path { year, day } => {
+            println!("{}", store.path(year, day).display());
+        }
+        InputCommand::Profiles => {
+            for profile in store.profiles()? {
+                println!("{profile}");
+            }
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+
+fn execute(cli: Cli) -> Result<ExitCode, Box<dyn Error>> {
+    let answers = cli.answers();
+
+    // only the commands reading inputs need the cache, `aoc new` works without one
+    let store = || -> Result<InputStore, Box<dyn Error>> {
+        let root = cli.cache_dir.clone()
+            .or_else(InputStore::default_root)
+            .ok_or("Cannot locate the input cache, pass --cache-dir or set AOC_CACHE_DIR")?;
+
+        Ok(InputStore::new(root, &cli.profile))
+    };
+
+    match cli.command {
+        Command::Run { year, day, part, input, record } => run(&store()?, &answers, year, day, part, input, record),
+        Command::Verify { year, day, all_profiles } => verify(&store()?, &answers, year, day, all_profiles),
+        Command::Bench { year, day, iterations, json } => bench(&store()?, year, day, iterations, json),
+        Command::New { year, day } => new(&cli.profile, &answers, year, day),
+        Command::Input(command) => self::input(&store()?, command)
+    }
+}
+
+
+fn main() -> ExitCode {
+    match execute(Cli::parse()) {
+        Ok(exit_code) => exit_code,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
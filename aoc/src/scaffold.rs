@@ -0,0 +1,319 @@
+use std::{env, error::Error, fs, path::{Path, PathBuf}};
+
+
+const MANIFEST_TEMPLATE: &str = r#"[package]
+name = "{crate}"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+aoc-core = { path = "../../aoc-core" }
+aoc-utils = { path = "../../aoc-utils" }
+
+[features]
+# Compile `src/input.txt` into the crate so the runner works without an input file
+embedded = []
+"#;
+
+
+const LIB_TEMPLATE: &str = r#"use aoc_core::{Answer, AocError, Result, Solution};
+
+
+fn parse_input(input: &str) -> Result<Vec<String>> {
+    Ok(input.lines().map(str::to_owned).collect())
+}
+
+
+fn solve_part_1(_lines: &[String]) -> Result<u64> {
+    Err(AocError::solve("part 1 is not solved yet"))
+}
+
+
+fn solve_part_2(_lines: &[String]) -> Result<u64> {
+    Err(AocError::solve("part 2 is not solved yet"))
+}
+
+
+#[cfg(feature = "embedded")]
+pub const INPUT: &str = include_str!("input.txt");
+
+
+pub struct Day{day};
+
+
+impl Solution for Day{day} {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, lines: &Self::Input) -> Result<Answer> {
+        solve_part_1(lines).map(Answer::from)
+    }
+
+    fn part2(&self, lines: &Self::Input) -> Result<Answer> {
+        solve_part_2(lines).map(Answer::from)
+    }
+
+    #[cfg(feature = "embedded")]
+    fn embedded_input(&self) -> Option<&'static str> {
+        Some(INPUT)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use crate::{parse_input, solve_part_1, solve_part_2};
+
+    const EXAMPLE: &str = "";
+
+
+    #[test]
+    #[ignore = "example input and answers are not filled in yet"]
+    fn test_example() {
+        let lines = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(solve_part_1(&lines).unwrap(), 0);
+        assert_eq!(solve_part_2(&lines).unwrap(), 0);
+    }
+}
+"#;
+
+
+/// A new day crate and the edits that wire it into the workspace.
+///
+/// Generated crates are named `aoc-<year>-day<day>` rather than `day<day>` like the
+/// original 2022 crates, so days of different years can live in one workspace.
+pub(crate) struct Scaffold {
+    pub(crate) year: u16,
+    pub(crate) day: u8
+}
+
+
+impl Scaffold {
+    pub(crate) fn crate_name(&self) -> String {
+        format!("aoc-{}-day{}", self.year, self.day)
+    }
+
+
+    fn directory(&self) -> String {
+        format!("aoc-{}/day{}", self.year, self.day)
+    }
+
+
+    fn render(&self, template: &str) -> String {
+        template
+            .replace("{crate}", &self.crate_name())
+            .replace("{day}", &self.day.to_string())
+    }
+
+
+    pub(crate) fn manifest(&self) -> String {
+        self.render(MANIFEST_TEMPLATE)
+    }
+
+
+    pub(crate) fn lib(&self) -> String {
+        self.render(LIB_TEMPLATE)
+    }
+
+
+    /// Adds the year's directory to the workspace members unless it is already there.
+    pub(crate) fn add_workspace_member(&self, manifest: &str) -> Option<String> {
+        let member = format!("\"aoc-{}/*\"", self.year);
+
+        if manifest.contains(&member) {
+            return Some(manifest.to_owned());
+        }
+
+        let members_end = manifest.find("members = [")
+            .and_then(|start| manifest[start..].find(']').map(|end| start + end))?;
+        let (before, after) = manifest.split_at(members_end);
+
+        Some(format!("{},\n    {member}\n{after}", before.trim_end()))
+    }
+
+
+    /// Adds the day as a dependency of the runner and to its `embedded` feature.
+    pub(crate) fn add_runner_dependency(&self, manifest: &str) -> Option<String> {
+        let crate_name = self.crate_name();
+        let dependency = format!("{crate_name} = {{ path = \"../{}\" }}", self.directory());
+
+        let mut lines: Vec<String> = manifest.lines().map(str::to_owned).collect();
+
+        let last_day = lines.iter().rposition(|line| line.contains("path = \"../aoc-") && !line.starts_with("aoc-core") && !line.starts_with("aoc-utils"))?;
+        lines.insert(last_day + 1, dependency);
+
+        let embedded = lines.iter_mut().find(|line| line.starts_with("embedded = ["))?;
+        let closing = embedded.rfind(']')?;
+        embedded.insert_str(closing, &format!(", \"{crate_name}/embedded\""));
+
+        Some(lines.join("\n") + "\n")
+    }
+
+
+    /// Appends the day to the chain of registrations in the runner's registry.
+    pub(crate) fn add_registration(&self, solutions: &str) -> Option<String> {
+        let registration = format!(
+            ".register({}, {}, {}::Day{})",
+            self.year,
+            self.day,
+            self.crate_name().replace('-', "_"),
+            self.day
+        );
+
+        let last_registration = solutions.rfind(".register(")?;
+        let end = last_registration + solutions[last_registration..].find(';')?;
+
+        Some(format!("{}\n        {registration}{}", &solutions[..end], &solutions[end..]))
+    }
+}
+
+
+/// The closest directory at or above the current one holding the workspace manifest.
+pub(crate) fn workspace_root() -> Result<PathBuf, Box<dyn Error>> {
+    let current = env::current_dir()?;
+
+    current.ancestors()
+        .find(|directory| fs::read_to_string(directory.join("Cargo.toml")).is_ok_and(|manifest| manifest.contains("[workspace]")))
+        .map(Path::to_path_buf)
+        .ok_or_else(|| "Not inside the Advent of Code workspace".into())
+}
+
+
+/// The file at `path` with `edit` applied, without writing it back yet.
+fn edited(path: PathBuf, edit: impl FnOnce(&str) -> Option<String>) -> Result<(PathBuf, String), Box<dyn Error>> {
+    let contents = fs::read_to_string(&path)?;
+    let edited = edit(&contents).ok_or_else(|| format!("Cannot find where to add the new day in {}", path.display()))?;
+
+    Ok((path, edited))
+}
+
+
+/// Creates the day crate and wires it into the workspace and the runner.
+///
+/// Every edit is worked out before anything is written, so a workspace the new day
+/// cannot be added to is left untouched.
+pub(crate) fn generate(root: &Path, scaffold: &Scaffold) -> Result<PathBuf, Box<dyn Error>> {
+    let directory = root.join(scaffold.directory());
+
+    if directory.exists() {
+        return Err(format!("{} already exists", directory.display()).into());
+    }
+
+    let solutions = root.join("aoc/src/solutions.rs");
+    let registration = format!(".register({}, {},", scaffold.year, scaffold.day);
+
+    if fs::read_to_string(&solutions)?.contains(&registration) {
+        return Err(format!("{} day {} is already registered", scaffold.year, scaffold.day).into());
+    }
+
+    let edits = [
+        edited(root.join("Cargo.toml"), |manifest| scaffold.add_workspace_member(manifest))?,
+        edited(root.join("aoc/Cargo.toml"), |manifest| scaffold.add_runner_dependency(manifest))?,
+        edited(solutions, |source| scaffold.add_registration(source))?
+    ];
+
+    fs::create_dir_all(directory.join("src"))?;
+    fs::write(directory.join("Cargo.toml"), scaffold.manifest())?;
+    fs::write(directory.join("src/lib.rs"), scaffold.lib())?;
+
+    for (path, contents) in edits {
+        fs::write(path, contents)?;
+    }
+
+    Ok(directory)
+}
+
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::scaffold::{Scaffold, generate};
+
+    const SCAFFOLD: Scaffold = Scaffold { year: 2023, day: 1 };
+
+
+    #[test]
+    fn test_templates() {
+        assert!(SCAFFOLD.manifest().contains("name = \"aoc-2023-day1\""));
+        assert!(SCAFFOLD.lib().contains("pub struct Day1;"));
+        assert!(SCAFFOLD.lib().contains("impl Solution for Day1 {"));
+        assert!(!SCAFFOLD.lib().contains("{day}"));
+    }
+
+
+    #[test]
+    fn test_add_workspace_member() {
+        let manifest = "[workspace]\nresolver = \"3\"\nmembers = [\n    \"aoc\",\n    \"aoc-2022/*\"\n]\n";
+
+        assert_eq!(
+            SCAFFOLD.add_workspace_member(manifest).unwrap(),
+            "[workspace]\nresolver = \"3\"\nmembers = [\n    \"aoc\",\n    \"aoc-2022/*\",\n    \"aoc-2023/*\"\n]\n"
+        );
+
+        let scaffold = Scaffold { year: 2022, day: 9 };
+        assert_eq!(scaffold.add_workspace_member(manifest).unwrap(), manifest);
+    }
+
+
+    #[test]
+    fn test_add_runner_dependency() {
+        let manifest = concat!(
+            "[dependencies]\n",
+            "aoc-core = { path = \"../aoc-core\" }\n",
+            "day8 = { path = \"../aoc-2022/day8\" }\n",
+            "serde = \"1\"\n",
+            "\n",
+            "[features]\n",
+            "embedded = [\"day8/embedded\"]\n"
+        );
+
+        assert_eq!(
+            SCAFFOLD.add_runner_dependency(manifest).unwrap(),
+            concat!(
+                "[dependencies]\n",
+                "aoc-core = { path = \"../aoc-core\" }\n",
+                "day8 = { path = \"../aoc-2022/day8\" }\n",
+                "aoc-2023-day1 = { path = \"../aoc-2023/day1\" }\n",
+                "serde = \"1\"\n",
+                "\n",
+                "[features]\n",
+                "embedded = [\"day8/embedded\", \"aoc-2023-day1/embedded\"]\n"
+            )
+        );
+    }
+
+
+    #[test]
+    fn test_add_registration() {
+        let solutions = "    registry\n        .register(2022, 4, day4::Day4)\n        .register(2022, 8, day8::Day8);\n\n    registry\n}\n";
+
+        assert_eq!(
+            SCAFFOLD.add_registration(solutions).unwrap(),
+            "    registry\n        .register(2022, 4, day4::Day4)\n        .register(2022, 8, day8::Day8)\n        .register(2023, 1, aoc_2023_day1::Day1);\n\n    registry\n}\n"
+        );
+    }
+
+
+    #[test]
+    fn test_generate_failure() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+
+        let manifest = "[workspace]\nmembers = [\n    \"aoc\"\n]\n";
+        fs::write(root.join("Cargo.toml"), manifest).unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), "[dependencies]\n").unwrap();
+        fs::write(root.join("aoc/src/solutions.rs"), "").unwrap();
+
+        assert!(generate(&root, &SCAFFOLD).is_err());
+        assert!(!root.join("aoc-2023").exists());
+        assert_eq!(fs::read_to_string(root.join("Cargo.toml")).unwrap(), manifest);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,16 @@
+use aoc_core::Registry;
+
+
+/// Every solution the runner knows about.
+pub(crate) fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    registry
+        .register(2022, 4, day4::Day4)
+        .register(2022, 5, day5::Day5)
+        .register(2022, 6, day6::Day6)
+        .register(2022, 7, day7::Day7)
+        .register(2022, 8, day8::Day8);
+
+    registry
+}
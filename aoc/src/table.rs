@@ -0,0 +1,66 @@
+use std::fmt;
+
+
+/// Plain text table with left aligned, space padded columns.
+pub(crate) struct Table {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>
+}
+
+
+impl Table {
+    pub(crate) fn new<const N: usize>(header: [&str; N]) -> Self {
+        Self {
+            header: header.iter().map(|column| column.to_string()).collect(),
+            rows: Vec::new()
+        }
+    }
+
+
+    pub(crate) fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+}
+
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths: Vec<usize> = (0..self.header.len())
+            .map(|column| {
+                std::iter::once(&self.header)
+                    .chain(&self.rows)
+                    .filter_map(|row| row.get(column))
+                    .map(|cell| cell.chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        for row in std::iter::once(&self.header).chain(&self.rows) {
+            let line = row.iter()
+                .zip(&widths)
+                .map(|(cell, &width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join("  ");
+
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use crate::table::Table;
+
+    #[test]
+    fn test_display() {
+        let mut table = Table::new(["day", "answer"]);
+        table.push(vec!["4".to_string(), "2".to_string()]);
+        table.push(vec!["12".to_string(), "CMZ".to_string()]);
+
+        assert_eq!(table.to_string(), "day  answer\n4    2\n12   CMZ\n");
+    }
+}
@@ -0,0 +1,196 @@
+use std::{path::Path, process::ExitCode};
+
+use aoc_core::{AnswerDatabase, AocError, InputStore, Part, Registry};
+
+use crate::{stored_input, table::Table};
+
+
+/// Which recorded answers to check.
+pub(crate) struct Filter {
+    pub(crate) profiles: Vec<String>,
+    pub(crate) year: Option<u16>,
+    pub(crate) day: Option<u8>
+}
+
+
+/// The first line of an error, short enough for a table cell, leaving out the source
+/// line and caret of parse errors.
+fn summary(error: &AocError) -> String {
+    error.to_string().lines().next().unwrap_or_default().to_owned()
+}
+
+
+/// Re-runs every registered solution that has recorded answers and prints how each
+/// part compares, failing when any answer no longer matches or when there was nothing
+/// to check at all.
+///
+/// Errors are summed up in the table and printed in full below it.
+pub(crate) fn verify(registry: &Registry, answers: &AnswerDatabase, store_root: &Path, filter: &Filter) -> ExitCode {
+    let mut table = Table::new(["profile", "year", "day", "part", "expected", "actual", "status"]);
+    let mut diagnostics = Vec::new();
+    let mut mismatches = 0;
+    let mut checked = 0;
+
+    for profile in &filter.profiles {
+        let store = InputStore::new(store_root, profile);
+
+        for ((year, day), solution) in registry.iter() {
+            if filter.year.is_some_and(|filter| filter != year) || filter.day.is_some_and(|filter| filter != day) {
+                continue;
+            }
+
+            let Some(recorded) = answers.day(profile, year, day) else {
+                continue;
+            };
+
+            let parts: Vec<(Part, &str)> = Part::ALL.into_iter()
+                .filter_map(|part| Some((part, recorded.get(part)?)))
+                .collect();
+
+            if parts.is_empty() {
+                continue;
+            }
+
+            let row = |part: Part, expected: &str, actual: String, status: &str| vec![
+                profile.clone(),
+                year.to_string(),
+                day.to_string(),
+                part.to_string(),
+                expected.to_string(),
+                actual,
+                status.to_string()
+            ];
+
+            let parsed = match stored_input(&store, solution, year, day).map(|input| solution.load(&input)) {
+                Some(Ok(parsed)) => parsed,
+                Some(Err(error)) => {
+                    for (part, expected) in parts {
+                        table.push(row(part, expected, format!("invalid input: {}", summary(&error)), "FAIL"));
+                        mismatches += 1;
+                    }
+
+                    diagnostics.push(format!("{profile} {year} day {day}: invalid input: {error}"));
+
+                    continue;
+                }
+                None => {
+                    for (part, expected) in parts {
+                        table.push(row(part, expected, "-".to_string(), "no input"));
+                    }
+
+                    continue;
+                }
+            };
+
+            for (part, expected) in parts {
+                checked += 1;
+
+                match parsed.part(part).map(|answer| answer.to_string()) {
+                    Ok(actual) if actual == expected => table.push(row(part, expected, actual, "ok")),
+                    Ok(actual) => {
+                        table.push(row(part, expected, actual, "MISMATCH"));
+                        mismatches += 1;
+                    }
+                    Err(error) => {
+                        table.push(row(part, expected, format!("error: {}", summary(&error)), "FAIL"));
+                        diagnostics.push(format!("{profile} {year} day {day} part {part}: {error}"));
+                        mismatches += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    print!("{table}");
+
+    for diagnostic in &diagnostics {
+        println!();
+        println!("{diagnostic}");
+    }
+
+    println!();
+    println!("{checked} answers checked, {mismatches} failed");
+
+    if mismatches > 0 {
+        ExitCode::FAILURE
+    } else if checked == 0 {
+        eprintln!("No recorded answer could be checked, record some with `aoc run <year> <day> --record`");
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use std::{fs, process::ExitCode};
+    use aoc_core::{Answer, AnswerDatabase, AocError, Input, InputStore, Part, Registry, Result, Solution};
+    use crate::verify::{Filter, summary, verify};
+
+    struct Count;
+
+    impl Solution for Count {
+        type Input = usize;
+
+        fn parse(&self, input: &str) -> Result<Self::Input> {
+            match input.lines().position(str::is_empty) {
+                Some(index) => Err(AocError::parse(index + 1, 1, "empty line").with_source(input)),
+                None => Ok(input.lines().count())
+            }
+        }
+
+        fn part1(&self, lines: &Self::Input) -> Result<Answer> {
+            Ok((*lines).into())
+        }
+
+        fn part2(&self, _: &Self::Input) -> Result<Answer> {
+            Err(AocError::solve("not solved"))
+        }
+    }
+
+
+    #[test]
+    fn test_summary() {
+        let error = AocError::parse(2, 1, "empty line").with_source("a\n\nb");
+        assert!(error.to_string().contains('\n'));
+        assert_eq!(summary(&error), "line 2, column 1: empty line");
+    }
+
+
+    #[test]
+    fn test_verify() {
+        let root = std::env::temp_dir().join(format!("aoc-verify-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        InputStore::new(&root, "alice").import(2022, 1, &Input::Text("a\nb\n")).unwrap();
+
+        let mut registry = Registry::new();
+        registry.register(2022, 1, Count);
+
+        let filter = |profile: &str| Filter { profiles: vec![profile.to_owned()], year: None, day: None };
+        let mut answers = AnswerDatabase::default();
+
+        answers.record("alice", 2022, 1, Part::One, &Answer::from(2_usize));
+        assert_eq!(verify(&registry, &answers, &root, &filter("alice")), ExitCode::SUCCESS);
+
+        answers.record("alice", 2022, 1, Part::One, &Answer::from(3_usize));
+        assert_eq!(verify(&registry, &answers, &root, &filter("alice")), ExitCode::FAILURE);
+
+        // a part that fails to solve is a failure too
+        answers.record("alice", 2022, 1, Part::One, &Answer::from(2_usize));
+        answers.record("alice", 2022, 1, Part::Two, &Answer::from(0_usize));
+        assert_eq!(verify(&registry, &answers, &root, &filter("alice")), ExitCode::FAILURE);
+
+        // carol's input does not parse
+        InputStore::new(&root, "carol").import(2022, 1, &Input::Text("a\n\nb\n")).unwrap();
+        answers.record("carol", 2022, 1, Part::One, &Answer::from(3_usize));
+        assert_eq!(verify(&registry, &answers, &root, &filter("carol")), ExitCode::FAILURE);
+
+        // bob has recorded answers but no input, so nothing gets checked
+        answers.record("bob", 2022, 1, Part::One, &Answer::from(2_usize));
+        assert_eq!(verify(&registry, &answers, &root, &filter("bob")), ExitCode::FAILURE);
+        assert_eq!(verify(&registry, &AnswerDatabase::default(), &root, &filter("alice")), ExitCode::FAILURE);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}